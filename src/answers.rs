@@ -0,0 +1,166 @@
+use crate::core::{Answer, Problem, input_dir};
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fmt::{Display, Formatter};
+use std::fs;
+use std::path::PathBuf;
+
+/// Lives next to a day's `input.txt` and is keyed by input file name.
+pub const ANSWERS_FILE: &str = "answers.toml";
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct RecordedAnswers {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub one: Option<Answer>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub two: Option<Answer>,
+}
+
+impl RecordedAnswers {
+    pub fn get(&self, problem: Problem) -> Option<&Answer> {
+        match problem {
+            Problem::One => self.one.as_ref(),
+            Problem::Two => self.two.as_ref(),
+        }
+    }
+
+    pub fn set(&mut self, problem: Problem, answer: Answer) {
+        match problem {
+            Problem::One => self.one = Some(answer),
+            Problem::Two => self.two = Some(answer),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Verdict {
+    Match(Answer),
+    Mismatch { expected: Answer, actual: Answer },
+    Unrecorded(Answer),
+}
+
+impl Verdict {
+    pub fn is_mismatch(&self) -> bool {
+        matches!(self, Verdict::Mismatch { .. })
+    }
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Verdict::Match(answer) => write!(f, "ok ({answer})"),
+            Verdict::Mismatch { expected, actual } => {
+                write!(f, "MISMATCH expected {expected}, got {actual}")
+            }
+            Verdict::Unrecorded(answer) => write!(f, "unrecorded ({answer})"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct AnswerStore {
+    inputs: BTreeMap<String, RecordedAnswers>,
+}
+
+impl AnswerStore {
+    pub fn path(day: &str) -> PathBuf {
+        input_dir().join(day).join(ANSWERS_FILE)
+    }
+
+    /// A day without an answers file simply has nothing recorded yet.
+    pub fn load(day: &str) -> Result<Self> {
+        let path = Self::path(day);
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+
+        let raw = fs::read_to_string(&path)
+            .with_context(|| format!("failed to read recorded answers at {}", path.display()))?;
+
+        Self::parse(&raw).with_context(|| format!("malformed answers file {}", path.display()))
+    }
+
+    pub fn parse(raw: &str) -> Result<Self> {
+        Ok(toml::from_str(raw)?)
+    }
+
+    pub fn save(&self, day: &str) -> Result<()> {
+        let path = Self::path(day);
+
+        fs::write(&path, toml::to_string(self)?)
+            .with_context(|| format!("failed to write recorded answers to {}", path.display()))
+    }
+
+    pub fn inputs(&self) -> impl Iterator<Item = (&str, &RecordedAnswers)> {
+        self.inputs
+            .iter()
+            .map(|(name, answers)| (name.as_str(), answers))
+    }
+
+    pub fn get(&self, input: &str, problem: Problem) -> Option<&Answer> {
+        self.inputs.get(input)?.get(problem)
+    }
+
+    pub fn record(&mut self, input: &str, problem: Problem, answer: Answer) {
+        self.inputs
+            .entry(String::from(input))
+            .or_default()
+            .set(problem, answer);
+    }
+
+    pub fn verify(&self, input: &str, problem: Problem, actual: Answer) -> Verdict {
+        match self.get(input, problem) {
+            Some(expected) if *expected == actual => Verdict::Match(actual),
+            Some(expected) => Verdict::Mismatch {
+                expected: expected.clone(),
+                actual,
+            },
+            None => Verdict::Unrecorded(actual),
+        }
+    }
+}
+
+#[cfg(test)]
+mod answers_tests {
+    use super::*;
+
+    #[test]
+    fn test_round_trip() -> Result<()> {
+        let mut store = AnswerStore::default();
+        store.record("input.txt", Problem::One, Answer::from(1043));
+        store.record("input.txt", Problem::Two, Answer::from(5963));
+        store.record(
+            "input-bob.txt",
+            Problem::One,
+            Answer::from(String::from("ABC")),
+        );
+
+        let raw = toml::to_string(&store)?;
+        assert_eq!(AnswerStore::parse(&raw)?, store);
+        Ok(())
+    }
+
+    #[test]
+    fn test_verify() -> Result<()> {
+        let store = AnswerStore::parse("[\"input.txt\"]\none = 1043\n")?;
+
+        assert_eq!(
+            store.verify("input.txt", Problem::One, Answer::from(1043)),
+            Verdict::Match(Answer::from(1043))
+        );
+        assert_eq!(
+            store.verify("input.txt", Problem::One, Answer::from(1042)),
+            Verdict::Mismatch {
+                expected: Answer::from(1043),
+                actual: Answer::from(1042)
+            }
+        );
+        assert_eq!(
+            store.verify("input.txt", Problem::Two, Answer::from(5963)),
+            Verdict::Unrecorded(Answer::from(5963))
+        );
+        Ok(())
+    }
+}
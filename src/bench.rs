@@ -0,0 +1,133 @@
+use crate::core::{NamedInput, Problem, Solver, TimedRun};
+use anyhow::{Result, anyhow};
+use std::fmt::{Display, Formatter};
+use std::time::Duration;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub max: Duration,
+}
+
+impl Stats {
+    pub fn from_samples(samples: &[Duration]) -> Option<Self> {
+        let mut sorted = samples.to_vec();
+        sorted.sort();
+
+        let mid = sorted.len() / 2;
+        let median = if sorted.len().is_multiple_of(2) {
+            (*sorted.get(mid.checked_sub(1)?)? + sorted[mid]) / 2
+        } else {
+            sorted[mid]
+        };
+
+        Some(Self {
+            min: *sorted.first()?,
+            median,
+            max: *sorted.last()?,
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct BenchReport {
+    pub day: usize,
+    pub input: String,
+    pub iterations: usize,
+    pub parse: Stats,
+    pub parts: Vec<(Problem, Stats)>,
+}
+
+impl Display for BenchReport {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        writeln!(
+            f,
+            "day {} / {} ({} iterations)\n{:<10} {:>12} {:>12} {:>12}",
+            self.day, self.input, self.iterations, "stage", "min", "median", "max"
+        )?;
+
+        let stages = std::iter::once((String::from("parse"), self.parse)).chain(
+            self.parts
+                .iter()
+                .map(|(problem, stats)| (format!("part {problem}"), *stats)),
+        );
+
+        for (stage, stats) in stages {
+            writeln!(
+                f,
+                "{:<10} {:>12} {:>12} {:>12}",
+                stage,
+                format!("{:.3?}", stats.min),
+                format!("{:.3?}", stats.median),
+                format!("{:.3?}", stats.max)
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Runs the solver `warmup` times untimed, then `iterations` times, collecting each stage separately.
+pub fn bench(
+    solver: &dyn Solver,
+    input: &NamedInput,
+    problems: &[Problem],
+    warmup: usize,
+    iterations: usize,
+) -> Result<BenchReport> {
+    if iterations == 0 {
+        return Err(anyhow!("at least one iteration is required"));
+    }
+
+    for _ in 0..warmup {
+        solver.run_timed(&input.data, problems)?;
+    }
+
+    let runs = (0..iterations)
+        .map(|_| solver.run_timed(&input.data, problems))
+        .collect::<Result<Vec<TimedRun>>>()?;
+
+    let stats = |samples: Vec<Duration>| {
+        Stats::from_samples(&samples).ok_or_else(|| anyhow!("no timings were collected"))
+    };
+
+    let parse = stats(runs.iter().map(|run| run.parse).collect())?;
+    let parts = problems
+        .iter()
+        .enumerate()
+        .map(|(idx, problem)| {
+            let samples = runs.iter().map(|run| run.parts[idx].elapsed).collect();
+            Ok((*problem, stats(samples)?))
+        })
+        .collect::<Result<Vec<(Problem, Stats)>>>()?;
+
+    Ok(BenchReport {
+        day: solver.day(),
+        input: input.name.clone(),
+        iterations,
+        parse,
+        parts,
+    })
+}
+
+#[cfg(test)]
+mod bench_tests {
+    use super::*;
+
+    #[test]
+    fn test_stats_from_samples() {
+        let samples = [5, 1, 3, 2].map(Duration::from_millis);
+        let stats = Stats::from_samples(&samples);
+
+        assert_eq!(
+            stats,
+            Some(Stats {
+                min: Duration::from_millis(1),
+                median: Duration::from_micros(2500),
+                max: Duration::from_millis(5),
+            })
+        );
+        assert_eq!(Stats::from_samples(&[]), None);
+    }
+}
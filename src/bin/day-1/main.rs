@@ -1,195 +1,129 @@
-use anyhow::{Result, anyhow};
+use anyhow::Result;
 use aoc_2025::core::*;
-
-#[derive(Debug)]
-struct Safe {
-    dial_state: usize,
-    zero_count: usize,
-    zero_crosses: usize,
+use aoc_2025::days::day_1::{DIAL_SIZE, DIAL_START, Day1, Tally, TargetCount, TraceFormat};
+use clap::Parser;
+use std::io::{self, Write};
+
+#[derive(Parser, Debug)]
+#[command(version, about, long_about = None)]
+struct Day1Args {
+    #[command(flatten)]
+    args: Args,
+
+    /// Number of positions on the dial
+    #[arg(long, default_value_t = DIAL_SIZE)]
+    dial_size: usize,
+
+    /// Position the dial points at before the first rotation
+    #[arg(long, default_value_t = DIAL_START)]
+    start: usize,
+
+    /// Print every rotation in this format instead of the answer
+    #[arg(
+        long,
+        value_enum,
+        value_name = "FORMAT",
+        conflicts_with_all = ["targets", "goal", "inputs", "all_inputs"]
+    )]
+    trace: Option<TraceFormat>,
+
+    /// Count stops on and clicks onto these positions instead of answering
+    #[arg(
+        long,
+        value_delimiter = ',',
+        value_name = "POSITIONS",
+        conflicts_with_all = ["goal", "inputs", "all_inputs"]
+    )]
+    targets: Vec<usize>,
+
+    /// Count stops on and clicks onto every position instead of answering
+    #[arg(
+        long,
+        conflicts_with_all = ["trace", "targets", "goal", "inputs", "all_inputs"]
+    )]
+    histogram: bool,
+
+    /// List the start positions that reach this count instead of answering
+    #[arg(long, value_name = "N", conflicts_with_all = ["inputs", "all_inputs"])]
+    goal: Option<usize>,
+
+    /// The count --goal is for
+    #[arg(long, value_enum, default_value_t = Tally::ZeroCount, requires = "goal")]
+    tally: Tally,
+
+    /// Also list single-instruction edits that reach --goal from --start
+    #[arg(long, requires = "goal")]
+    edits: bool,
+
+    /// Apply rotations as they are read, for inputs too big to hold in memory
+    #[arg(
+        long,
+        conflicts_with_all = ["trace", "targets", "histogram", "goal", "inputs", "all_inputs"]
+    )]
+    stream: bool,
 }
 
-#[derive(Debug)]
-enum Direction {
-    Left(usize),
-    Right(usize),
-}
+fn main() -> Result<()> {
+    let cli = Day1Args::parse();
+    let day = Day1::new(cli.dial_size, cli.start)?;
 
-impl Direction {
-    fn parse(input: Vec<String>) -> Result<Vec<Direction>> {
-        Ok(input
-            .iter()
-            .map(|line| {
-                let (direction, count) = line.split_at(1);
-                match direction {
-                    "L" => Ok(Direction::Left(
-                        count.parse().map_err(|_| anyhow!("Parse Error"))?,
-                    )),
-                    "R" => Ok(Direction::Right(
-                        count.parse().map_err(|_| anyhow!("Parse Error"))?,
-                    )),
-                    _ => Err(anyhow!("Unexpected Input")),
-                }
-            })
-            .collect::<Result<Vec<Direction>, anyhow::Error>>()?)
+    if cli.stream {
+        let tallies = day.stream(cli.args.input.open(&day_name(Day1::DAY))?)?;
+        println!("{}", tallies.part(cli.args.problem)?);
+        return Ok(());
     }
-}
 
-impl Safe {
-    pub fn new(dial_state: usize) -> Self {
-        Self {
-            dial_state,
-            zero_count: 0,
-            zero_crosses: 0,
-        }
+    if cli.trace.is_none() && cli.targets.is_empty() && !cli.histogram && cli.goal.is_none() {
+        return run_problems_with(&day, cli.args);
     }
 
-    pub fn rotate(&mut self, direction: &Direction) {
-        let result = match direction {
-            Direction::Left(count) => self.dial_state as isize - *count as isize,
-            Direction::Right(count) => self.dial_state as isize + *count as isize,
-        };
-
-        let (wrapped_result, crosses) = self.wrap(result);
+    let input = cli.args.input.read(&day_name(Day1::DAY))?;
+    let directions = parse_for_day(&day, &input)?;
 
-        self.dial_state = wrapped_result;
-        self.zero_crosses += crosses;
-
-        if self.dial_state == 0 {
-            self.zero_count += 1;
-        }
+    if let Some(format) = cli.trace {
+        let trace = format.render(&day.trace(&directions))?;
+        io::stdout().write_all(trace.as_bytes())?;
+        return Ok(());
     }
 
-    fn wrap(&self, input: isize) -> (usize, usize) {
-        let wrapped = ((input % 100) + 100) % 100;
-        let idial_state = self.dial_state as isize;
-
-        let crosses = if input < idial_state {
-            (idial_state - 1).div_euclid(100) - (input).div_euclid(100)
-        } else {
-            (input - 1) / 100 - idial_state / 100
-        };
-
-        (wrapped as usize, crosses as usize)
+    if let Some(goal) = cli.goal {
+        let starts = day.starts_for(&directions, cli.tally, goal)?;
+        println!(
+            "starts: {}",
+            starts
+                .iter()
+                .map(usize::to_string)
+                .collect::<Vec<String>>()
+                .join(",")
+        );
+        if cli.edits {
+            for edit in day.edits_for(&directions, cli.tally, goal)? {
+                println!("{edit}");
+            }
+        }
+        return Ok(());
     }
-}
 
-fn problem_one(input: Vec<String>) -> Result<()> {
-    let mut safe = Safe::new(50);
-    let parsed = Direction::parse(input)?;
-
-    parsed.iter().for_each(|direction| safe.rotate(direction));
-
-    println!("{}", safe.zero_count);
-
-    Ok(())
-}
-
-fn problem_two(input: Vec<String>) -> Result<()> {
-    let mut safe = Safe::new(50);
-    let parsed = Direction::parse(input)?;
-
-    parsed.iter().for_each(|direction| safe.rotate(direction));
-
-    println!("{}", safe.zero_crosses + safe.zero_count);
-
-    Ok(())
-}
+    let counts = if cli.histogram {
+        day.histogram(&directions).counts().collect()
+    } else {
+        day.count_targets(&directions, &cli.targets)?
+    };
+    print_counts(&counts);
 
-fn main() -> Result<()> {
-    let input = get_lines("day-1")?;
-    run_problems(problem_one, problem_two, input)?;
     Ok(())
 }
 
-#[cfg(test)]
-mod day_1_tests {
-    use crate::*;
-
-    const INPUT: &str = "L68
-L30
-R48
-L5
-R60
-L55
-L1
-L99
-R14
-L82";
-
-    #[test]
-    fn test_problem_one() -> Result<()> {
-        let result = Direction::parse(INPUT.lines().map(String::from).collect())?;
-        let mut safe = Safe::new(50);
-
-        result.iter().for_each(|direction| safe.rotate(direction));
-        assert_eq!(safe.zero_count, 3);
-        Ok(())
-    }
-
-    #[test]
-    fn test_problem_two() -> Result<()> {
-        let result = Direction::parse(INPUT.lines().map(String::from).collect())?;
-        let mut safe = Safe::new(50);
-
-        result.iter().for_each(|direction| safe.rotate(direction));
-        println!("{:?}", safe);
-        assert_eq!(safe.zero_crosses + safe.zero_count, 6);
-        Ok(())
-    }
-
-    #[test]
-    fn test_problem_multi_wrap() -> Result<()> {
-        let result = Direction::parse(vec![String::from("R1000")])?;
-        let mut safe = Safe::new(50);
-
-        result.iter().for_each(|direction| safe.rotate(direction));
-        assert_eq!(safe.zero_crosses, 10);
-        Ok(())
-    }
-
-    #[test]
-    fn test_problem_left_zero() -> Result<()> {
-        let result = Direction::parse(vec![String::from("L22")])?;
-        let mut safe = Safe::new(22);
-
-        result.iter().for_each(|direction| safe.rotate(direction));
-        assert_eq!(safe.zero_crosses + safe.zero_count, 1);
-        Ok(())
-    }
-
-    #[test]
-    fn test_problem_right_zero() -> Result<()> {
-        let result = Direction::parse(vec![String::from("R199")])?;
-        let mut safe = Safe::new(1);
-
-        result.iter().for_each(|direction| safe.rotate(direction));
-        assert_eq!(safe.zero_crosses + safe.zero_count, 2);
-        Ok(())
-    }
-
-    #[test]
-    fn test_problem_one_input() -> Result<()> {
-        let input = get_lines("day-1")?;
-        let mut safe = Safe::new(50);
-        let parsed = Direction::parse(input)?;
-
-        parsed.iter().for_each(|direction| safe.rotate(direction));
-
-        assert_eq!(safe.zero_count, 1043);
-
-        Ok(())
-    }
-
-    #[test]
-    fn test_problem_two_input() -> Result<()> {
-        let input = get_lines("day-1")?;
-        let mut safe = Safe::new(50);
-        let parsed = Direction::parse(input)?;
-
-        parsed.iter().for_each(|direction| safe.rotate(direction));
-
-        assert_eq!(safe.zero_crosses + safe.zero_count, 5963);
-
-        Ok(())
+fn print_counts(counts: &[TargetCount]) {
+    for count in counts {
+        println!(
+            "position {}: stopped {}, touched {}",
+            count.position, count.stops, count.touches
+        );
     }
+    println!(
+        "total: stopped {}, touched {}",
+        counts.iter().map(|count| count.stops).sum::<usize>(),
+        counts.iter().map(|count| count.touches).sum::<usize>()
+    );
 }
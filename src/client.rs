@@ -0,0 +1,194 @@
+use anyhow::{Context, Result, anyhow};
+use std::path::{Path, PathBuf};
+use std::{env, fs};
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+pub const YEAR: usize = 2025;
+
+/// Takes precedence over the session file.
+pub const SESSION_VAR: &str = "AOC_SESSION";
+
+const USER_AGENT: &str = "github.com/dpchamps/aoc2025 by dpchamps";
+
+/// `$XDG_CONFIG_HOME/aoc/session`, falling back to `~/.config/aoc/session`.
+pub fn session_file() -> Option<PathBuf> {
+    env::var_os("XDG_CONFIG_HOME")
+        .map(PathBuf::from)
+        .or_else(|| env::var_os("HOME").map(|home| PathBuf::from(home).join(".config")))
+        .map(|config| config.join("aoc").join("session"))
+}
+
+pub fn session_token() -> Result<String> {
+    if let Ok(token) = env::var(SESSION_VAR)
+        && !token.trim().is_empty()
+    {
+        return Ok(token.trim().to_string());
+    }
+
+    let path =
+        session_file().ok_or_else(|| anyhow!("set {SESSION_VAR} or create a session file"))?;
+    let token = fs::read_to_string(&path).with_context(|| {
+        format!(
+            "no session token: set {SESSION_VAR} or write it to {}",
+            path.display()
+        )
+    })?;
+
+    Ok(token.trim().to_string())
+}
+
+pub struct AocClient {
+    base_url: String,
+    session: String,
+    agent: ureq::Agent,
+}
+
+impl AocClient {
+    pub fn new(base_url: &str, session: &str) -> Self {
+        Self {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session: session.to_string(),
+            agent: ureq::AgentBuilder::new().user_agent(USER_AGENT).build(),
+        }
+    }
+
+    pub fn day_url(&self, day: usize) -> String {
+        format!("{}/{YEAR}/day/{day}", self.base_url)
+    }
+
+    pub fn fetch_input(&self, day: usize) -> Result<String> {
+        let url = format!("{}/input", self.day_url(day));
+
+        self.agent
+            .get(&url)
+            .set("Cookie", &format!("session={}", self.session))
+            .call()
+            .with_context(|| format!("failed to download {url}"))?
+            .into_string()
+            .with_context(|| format!("failed to read response body from {url}"))
+    }
+
+    /// Posts an answer and returns the raw HTML so the caller can interpret the verdict.
+    pub fn submit_answer(&self, day: usize, level: u8, answer: &str) -> Result<String> {
+        let url = format!("{}/answer", self.day_url(day));
+
+        self.agent
+            .post(&url)
+            .set("Cookie", &format!("session={}", self.session))
+            .send_form(&[("level", &level.to_string()), ("answer", answer)])
+            .with_context(|| format!("failed to submit to {url}"))?
+            .into_string()
+            .with_context(|| format!("failed to read response body from {url}"))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Fetched {
+    Cached(PathBuf),
+    Downloaded(PathBuf),
+}
+
+/// An empty file (as left by `aoc new`) does not count as a cached input.
+pub fn is_cached(path: &Path) -> bool {
+    fs::metadata(path).is_ok_and(|metadata| metadata.len() > 0)
+}
+
+/// Downloads the day's input to `path` unless a non-empty copy is already there.
+pub fn fetch_input_cached(client: &AocClient, day: usize, path: &Path) -> Result<Fetched> {
+    if is_cached(path) {
+        return Ok(Fetched::Cached(path.to_path_buf()));
+    }
+
+    let input = client.fetch_input(day)?;
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    fs::write(path, input)
+        .with_context(|| format!("failed to cache input at {}", path.display()))?;
+
+    Ok(Fetched::Downloaded(path.to_path_buf()))
+}
+
+#[cfg(test)]
+pub(crate) mod stub_server {
+    use std::io::{BufRead, BufReader, Read, Write};
+    use std::net::TcpListener;
+    use std::sync::mpsc::{Receiver, channel};
+    use std::thread;
+
+    /// Serves one canned response per entry in `responses`, sending each raw request back.
+    pub fn serve(responses: Vec<(u16, &'static str)>) -> (String, Receiver<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").expect("failed to bind stub server");
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        let (sender, receiver) = channel();
+
+        thread::spawn(move || {
+            for (status, body) in responses {
+                let Ok((stream, _)) = listener.accept() else {
+                    return;
+                };
+                let mut reader = BufReader::new(stream);
+                let mut request = String::new();
+                let mut content_length = 0;
+
+                loop {
+                    let mut line = String::new();
+                    if reader.read_line(&mut line).unwrap_or(0) == 0 || line == "\r\n" {
+                        break;
+                    }
+                    if let Some(length) = line.to_lowercase().strip_prefix("content-length:") {
+                        content_length = length.trim().parse().unwrap_or(0);
+                    }
+                    request.push_str(&line);
+                }
+
+                let mut payload = vec![0; content_length];
+                reader.read_exact(&mut payload).ok();
+                request.push_str(&String::from_utf8_lossy(&payload));
+                sender.send(request).ok();
+
+                let response = format!(
+                    "HTTP/1.1 {status} OK\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                    body.len()
+                );
+                reader.get_mut().write_all(response.as_bytes()).ok();
+            }
+        });
+
+        (base_url, receiver)
+    }
+}
+
+#[cfg(test)]
+mod client_tests {
+    use super::*;
+
+    #[test]
+    fn test_fetch_downloads_once() -> Result<()> {
+        let (base_url, requests) = stub_server::serve(vec![(200, "R5\nL10\n")]);
+        let client = AocClient::new(&base_url, "abc123");
+        let dir = env::temp_dir().join(format!("aoc-fetch-{}", std::process::id()));
+        let path = dir.join("day-1").join("input.txt");
+
+        let first = fetch_input_cached(&client, 1, &path)?;
+        let request = requests.recv()?;
+        let second = fetch_input_cached(&client, 1, &path)?;
+        let contents = fs::read_to_string(&path)?;
+        fs::remove_dir_all(&dir)?;
+
+        assert_eq!(first, Fetched::Downloaded(path.clone()));
+        assert_eq!(second, Fetched::Cached(path));
+        assert_eq!(contents, "R5\nL10\n");
+        assert!(request.starts_with("GET /2025/day/1/input HTTP/1.1"));
+        assert!(request.contains("session=abc123"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_fetch_reports_http_errors() {
+        let (base_url, _requests) = stub_server::serve(vec![(404, "not yet")]);
+        let client = AocClient::new(&base_url, "abc123");
+
+        assert!(client.fetch_input(25).is_err());
+    }
+}
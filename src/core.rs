@@ -1,43 +1,640 @@
-use anyhow::Result;
+use crate::viz::{Frame, Recorder};
+use anyhow::{Context, Result, anyhow};
 use clap::{Parser, ValueEnum};
-use std::{env, fs};
+use itertools::Itertools;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fmt::{Display, Formatter};
+use std::io::{BufRead, BufReader, Read};
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+use std::{env, fs, io};
 
-pub fn get_data(day: &str) -> Result<String> {
-    let path = format!(
-        "{}/src/bin/{}/input.txt",
-        env::current_dir()?.display(),
-        day
-    );
+/// Overrides the directory holding each day's `{day}/input.txt`.
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+
+pub fn day_name(day: usize) -> String {
+    format!("day-{day}")
+}
+
+pub fn input_dir() -> PathBuf {
+    env::var_os(INPUT_DIR_VAR)
+        .map(PathBuf::from)
+        .unwrap_or_else(|| PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("src/bin"))
+}
 
-    Ok(fs::read_to_string(path)?)
+pub fn input_path(day: &str) -> PathBuf {
+    input_dir().join(day).join("input.txt")
+}
+
+pub fn get_data(day: &str) -> Result<String> {
+    read_input_file(&input_path(day))
 }
 
 pub fn get_lines(day: &str) -> Result<Vec<String>> {
     Ok(get_data(day)?.lines().map(String::from).collect())
 }
 
-#[derive(Debug, PartialEq, ValueEnum, Clone)]
-enum Problem {
+fn read_input_file(path: &Path) -> Result<String> {
+    fs::read_to_string(path)
+        .with_context(|| format!("failed to read puzzle input at {}", path.display()))
+}
+
+/// Every `input*.txt` in `dir`, sorted by file name so tables come out in a stable order.
+pub fn discover_inputs(dir: &Path) -> Result<Vec<PathBuf>> {
+    discover_files(dir, |name| {
+        name.starts_with("input") && name.ends_with(".txt")
+    })
+}
+
+fn discover_files(dir: &Path, predicate: impl Fn(&str) -> bool) -> Result<Vec<PathBuf>> {
+    let entries = fs::read_dir(dir)
+        .with_context(|| format!("failed to list puzzle inputs in {}", dir.display()))?;
+
+    let mut paths = entries
+        .map(|entry| Ok(entry?.path()))
+        .collect::<Result<Vec<PathBuf>>>()?
+        .into_iter()
+        .filter(|path| path.is_file())
+        .filter(|path| {
+            path.file_name()
+                .and_then(|name| name.to_str())
+                .is_some_and(&predicate)
+        })
+        .collect::<Vec<PathBuf>>();
+
+    if paths.is_empty() {
+        return Err(anyhow!("no puzzle inputs found in {}", dir.display()));
+    }
+
+    paths.sort();
+    Ok(paths)
+}
+
+#[derive(Debug, Clone)]
+pub struct NamedInput {
+    pub name: String,
+    pub data: String,
+}
+
+impl NamedInput {
+    fn from_path(path: &Path) -> Result<Self> {
+        Ok(Self {
+            name: path
+                .file_name()
+                .map(|name| name.to_string_lossy().into_owned())
+                .unwrap_or_else(|| path.display().to_string()),
+            data: read_input_file(path)?,
+        })
+    }
+}
+
+#[derive(clap::Args, Debug, Clone, Default)]
+#[group(multiple = false)]
+pub struct InputArgs {
+    /// Read the puzzle input from this file instead of the day's input.txt
+    #[arg(long)]
+    pub input: Option<PathBuf>,
+
+    /// Read the puzzle input from stdin
+    #[arg(long)]
+    pub stdin: bool,
+
+    /// Run against every input*.txt in the day's directory
+    #[arg(long)]
+    pub all_inputs: bool,
+
+    /// Run against every .txt file in this directory
+    #[arg(long, value_name = "DIR")]
+    pub inputs: Option<PathBuf>,
+}
+
+impl InputArgs {
+    pub fn read(&self, day: &str) -> Result<String> {
+        match (&self.input, self.stdin) {
+            (Some(path), _) => read_input_file(path),
+            (None, true) => {
+                let mut buffer = String::new();
+                io::stdin()
+                    .read_to_string(&mut buffer)
+                    .context("failed to read puzzle input from stdin")?;
+                Ok(buffer)
+            }
+            (None, false) => get_data(day),
+        }
+    }
+
+    /// Like [`InputArgs::read`], but hands back a reader so the input can be streamed.
+    pub fn open(&self, day: &str) -> Result<Box<dyn BufRead>> {
+        if self.stdin {
+            return Ok(Box::new(io::stdin().lock()));
+        }
+
+        let path = self.input.clone().unwrap_or_else(|| input_path(day));
+        let file = fs::File::open(&path)
+            .with_context(|| format!("failed to open puzzle input at {}", path.display()))?;
+
+        Ok(Box::new(BufReader::new(file)))
+    }
+
+    pub fn read_all(&self, day: &str) -> Result<Vec<NamedInput>> {
+        let paths = match (&self.inputs, self.all_inputs) {
+            (Some(dir), _) => discover_files(dir, |name| name.ends_with(".txt"))?,
+            (None, true) => discover_inputs(&input_dir().join(day))?,
+            (None, false) => {
+                let name = match (&self.input, self.stdin) {
+                    (Some(path), _) => path.display().to_string(),
+                    (None, true) => String::from("stdin"),
+                    (None, false) => String::from("input.txt"),
+                };
+
+                return Ok(vec![NamedInput {
+                    name,
+                    data: self.read(day)?,
+                }]);
+            }
+        };
+
+        paths
+            .iter()
+            .map(|path| NamedInput::from_path(path))
+            .collect()
+    }
+}
+
+/// Renders one row per input with a column per requested part; failed inputs show their error.
+pub fn format_answer_table(parts: &[Problem], rows: &[(String, Result<Vec<Answer>>)]) -> String {
+    let header = std::iter::once(String::from("input"))
+        .chain(parts.iter().map(|part| format!("part {part}")))
+        .collect::<Vec<String>>();
+
+    let body = rows
+        .iter()
+        .map(|(name, result)| {
+            let cells = match result {
+                Ok(answers) => answers.iter().map(Answer::to_string).collect(),
+                Err(error) => vec![format!("error: {error}")],
+            };
+
+            std::iter::once(name.clone())
+                .chain(cells)
+                .collect::<Vec<String>>()
+        })
+        .collect::<Vec<Vec<String>>>();
+
+    let widths = std::iter::once(&header).chain(body.iter()).fold(
+        vec![0; header.len()],
+        |mut widths, row| {
+            for (width, cell) in widths.iter_mut().zip(row) {
+                *width = usize::max(*width, cell.len());
+            }
+            widths
+        },
+    );
+
+    std::iter::once(&header)
+        .chain(body.iter())
+        .map(|row| {
+            row.iter()
+                .enumerate()
+                .map(|(idx, cell)| {
+                    format!(
+                        "{cell:<width$}",
+                        width = widths.get(idx).copied().unwrap_or(0)
+                    )
+                })
+                .join(" | ")
+                .trim_end()
+                .to_string()
+        })
+        .join("\n")
+}
+
+#[derive(Debug, PartialEq, ValueEnum, Clone, Copy)]
+pub enum Problem {
+    #[value(alias = "1")]
     One,
+    #[value(alias = "2")]
     Two,
 }
 
+impl Problem {
+    pub fn number(&self) -> u8 {
+        match self {
+            Problem::One => 1,
+            Problem::Two => 2,
+        }
+    }
+}
+
+/// The part as users see it in every report, e.g. `part one`.
+impl Display for Problem {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Problem::One => write!(f, "one"),
+            Problem::Two => write!(f, "two"),
+        }
+    }
+}
+
 #[derive(Parser, Debug)]
 #[command(version, about, long_about = None)]
-struct Args {
-    #[arg(short, long, value_enum, default_value_t = Problem::One)]
-    problem: Problem,
+pub struct Args {
+    #[arg(short, long, visible_alias = "part", value_enum, default_value_t = Problem::One)]
+    pub problem: Problem,
+
+    #[command(flatten)]
+    pub input: InputArgs,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum Answer {
+    Number(usize),
+    Text(String),
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Answer::Number(number) => write!(f, "{number}"),
+            Answer::Text(text) => write!(f, "{text}"),
+        }
+    }
+}
+
+impl From<usize> for Answer {
+    fn from(value: usize) -> Self {
+        Answer::Number(value)
+    }
+}
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Answer::Text(value)
+    }
+}
+
+/// Where and why a puzzle input failed to parse. Lines and columns are 1-based.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub day: Option<usize>,
+    pub line: usize,
+    pub column: usize,
+    pub text: String,
+    pub expected: String,
+}
+
+impl ParseError {
+    /// An error at the start of `text`; callers place it with [`ParseError::at`] or
+    /// [`ParseError::within`] as it propagates out to the whole input.
+    pub fn new(text: impl Into<String>, expected: impl Into<String>) -> Self {
+        Self {
+            day: None,
+            line: 1,
+            column: 1,
+            text: text.into(),
+            expected: expected.into(),
+        }
+    }
+
+    /// Moves a position relative to a fragment into the enclosing input, where the fragment
+    /// starts at `line` and `column`.
+    pub fn at(mut self, line: usize, column: usize) -> Self {
+        if self.line == 1 {
+            self.column += column - 1;
+        }
+        self.line += line - 1;
+        self
+    }
+
+    /// Like [`ParseError::at`] for a `fragment` borrowed from `source`.
+    pub fn within(self, source: &str, fragment: &str) -> Self {
+        let (line, column) = locate(source, fragment);
+        self.at(line, column)
+    }
+
+    pub fn for_day(mut self, day: usize) -> Self {
+        self.day = Some(day);
+        self
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if let Some(day) = self.day {
+            write!(f, "day {day}, ")?;
+        }
+
+        write!(
+            f,
+            "line {}, column {}: expected {}, found {:?}",
+            self.line, self.column, self.expected, self.text
+        )
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// The line and column where `fragment` starts, provided it is a slice of `source`.
+/// Anything else is placed at the start of `source`.
+pub fn locate(source: &str, fragment: &str) -> (usize, usize) {
+    let offset = (fragment.as_ptr() as usize).wrapping_sub(source.as_ptr() as usize);
+    let Some(before) = source.get(..offset) else {
+        return (1, 1);
+    };
+
+    let line = before.matches('\n').count() + 1;
+    let column = before
+        .rsplit('\n')
+        .next()
+        .map_or(0, |row| row.chars().count())
+        + 1;
+
+    (line, column)
+}
+
+/// Parses with `solution`, tagging any [`ParseError`] with the day it came from.
+pub fn parse_for_day<S: Solution>(solution: &S, input: &str) -> Result<S::Input> {
+    solution
+        .parse(input)
+        .map_err(|error| match error.downcast::<ParseError>() {
+            Ok(error) => error.for_day(S::DAY).into(),
+            Err(error) => error,
+        })
+}
+
+/// A single day's puzzle: parse the raw input once, then answer either part from it.
+pub trait Solution {
+    const DAY: usize;
+
+    type Input;
+
+    fn parse(&self, input: &str) -> Result<Self::Input>;
+
+    fn part_one(&self, input: &Self::Input) -> Result<Answer>;
+
+    fn part_two(&self, input: &Self::Input) -> Result<Answer>;
+
+    fn part(&self, input: &Self::Input, problem: Problem) -> Result<Answer> {
+        match problem {
+            Problem::One => self.part_one(input),
+            Problem::Two => self.part_two(input),
+        }
+    }
+
+    /// Records frames of the solve for `aoc viz`; most days have nothing to show.
+    fn visualize(&self, _input: &Self::Input, _recorder: &mut Recorder) -> Result<()> {
+        Err(anyhow!("day {} has no visualization", Self::DAY))
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct TimedAnswer {
+    pub problem: Problem,
+    pub answer: Answer,
+    pub elapsed: Duration,
+}
+
+/// Answers from one [`Solver::run_timed`] call, with parse time kept apart from each part.
+#[derive(Debug, Clone)]
+pub struct TimedRun {
+    pub parse: Duration,
+    pub parts: Vec<TimedAnswer>,
+}
+
+impl TimedRun {
+    pub fn answers(&self) -> Vec<Answer> {
+        self.parts.iter().map(|part| part.answer.clone()).collect()
+    }
+
+    pub fn records(&self, day: usize, input: &str) -> Vec<AnswerRecord> {
+        self.parts
+            .iter()
+            .map(|part| AnswerRecord {
+                day,
+                part: part.problem.number(),
+                answer: part.answer.clone(),
+                duration_ms: part.elapsed.as_secs_f64() * 1000.0,
+                input: String::from(input),
+            })
+            .collect()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Default, ValueEnum)]
+pub enum OutputFormat {
+    #[default]
+    Text,
+    /// One JSON object per answer, one per line
+    Json,
+}
+
+/// The machine-readable shape of a single answer, as emitted by `--format json`.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct AnswerRecord {
+    pub day: usize,
+    pub part: u8,
+    pub answer: Answer,
+    pub duration_ms: f64,
+    pub input: String,
 }
 
-pub fn run_problems<T>(
-    problem_one: fn(T) -> Result<()>,
-    problem_two: fn(T) -> Result<()>,
-    input: T,
-) -> Result<()> {
-    let args = Args::parse();
+impl Display for TimedRun {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let parts = self
+            .parts
+            .iter()
+            .map(|part| format!("part {} {:.3?}", part.problem, part.elapsed))
+            .join(" / ");
+
+        write!(f, "parse {:.3?} / {parts}", self.parse)
+    }
+}
+
+/// Type-erased [`Solution`] so that days with different input types can share a registry.
+pub trait Solver {
+    fn day(&self) -> usize;
+
+    fn run_timed(&self, input: &str, problems: &[Problem]) -> Result<TimedRun>;
+
+    fn run(&self, input: &str, problems: &[Problem]) -> Result<Vec<Answer>> {
+        Ok(self.run_timed(input, problems)?.answers())
+    }
+
+    fn frames(&self, input: &str) -> Result<Vec<Frame>>;
+}
+
+impl<S: Solution> Solver for S {
+    fn day(&self) -> usize {
+        S::DAY
+    }
+
+    fn run_timed(&self, input: &str, problems: &[Problem]) -> Result<TimedRun> {
+        let start = Instant::now();
+        let parsed = parse_for_day(self, input)?;
+        let parse = start.elapsed();
+
+        let parts = problems
+            .iter()
+            .map(|problem| {
+                let start = Instant::now();
+                let answer = self.part(&parsed, *problem)?;
+
+                Ok(TimedAnswer {
+                    problem: *problem,
+                    answer,
+                    elapsed: start.elapsed(),
+                })
+            })
+            .collect::<Result<Vec<TimedAnswer>>>()?;
+
+        Ok(TimedRun { parse, parts })
+    }
+
+    fn frames(&self, input: &str) -> Result<Vec<Frame>> {
+        let parsed = parse_for_day(self, input)?;
+        let mut recorder = Recorder::on();
+        Solution::visualize(self, &parsed, &mut recorder)?;
+
+        Ok(recorder.into_frames())
+    }
+}
+
+pub fn run_problems<S: Solution>(solution: &S) -> Result<()> {
+    run_problems_with(solution, Args::parse())
+}
+
+/// Like [`run_problems`], for a binary that parses [`Args`] alongside options of its own.
+pub fn run_problems_with<S: Solution>(solution: &S, args: Args) -> Result<()> {
+    let inputs = args.input.read_all(&day_name(S::DAY))?;
+
+    if let [input] = inputs.as_slice() {
+        let parsed = parse_for_day(solution, &input.data)?;
+        println!("{}", solution.part(&parsed, args.problem)?);
+        return Ok(());
+    }
+
+    let rows = inputs
+        .into_iter()
+        .map(|input| (input.name, solution.run(&input.data, &[args.problem])))
+        .collect::<Vec<(String, Result<Vec<Answer>>)>>();
+    println!("{}", format_answer_table(&[args.problem], &rows));
+
+    Ok(())
+}
+
+#[derive(Default)]
+pub struct Registry {
+    days: BTreeMap<usize, Box<dyn Solver>>,
+}
+
+impl Registry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn register(&mut self, solver: impl Solver + 'static) -> &mut Self {
+        self.days.insert(solver.day(), Box::new(solver));
+        self
+    }
+
+    pub fn get(&self, day: usize) -> Result<&dyn Solver> {
+        self.days
+            .get(&day)
+            .map(|solver| solver.as_ref())
+            .ok_or_else(|| anyhow!("no solver registered for day {day}"))
+    }
+
+    pub fn days(&self) -> impl Iterator<Item = usize> + '_ {
+        self.days.keys().copied()
+    }
+}
+
+#[cfg(test)]
+mod core_tests {
+    use super::*;
+
+    #[test]
+    fn test_missing_input_names_path() {
+        let error = get_data("day-0").unwrap_err();
+        let expected = input_path("day-0");
+
+        assert!(format!("{error}").contains(&expected.display().to_string()));
+    }
+
+    #[test]
+    fn test_parse_error_location() {
+        let input = "R5\nL10,Q3\n";
+        let line = &input[3..9];
+        let fragment = &line[4..];
+
+        assert_eq!(locate(input, fragment), (2, 5));
+        assert_eq!(locate(input, &String::from("Q3")), (1, 1));
+
+        let error = ParseError::new("Q", "`L` or `R`")
+            .within(line, fragment)
+            .within(input, line)
+            .for_day(1);
+        assert_eq!((error.line, error.column), (2, 5));
+        assert_eq!(
+            error.to_string(),
+            "day 1, line 2, column 5: expected `L` or `R`, found \"Q\""
+        );
+    }
+
+    #[test]
+    fn test_discover_inputs() -> Result<()> {
+        let dir = env::temp_dir().join(format!("aoc-discover-{}", std::process::id()));
+        fs::create_dir_all(&dir)?;
+        for name in ["input.txt", "input-bob.txt", "notes.txt", "answers.toml"] {
+            fs::write(dir.join(name), "")?;
+        }
+
+        let found = discover_inputs(&dir)?
+            .iter()
+            .filter_map(|path| path.file_name()?.to_str().map(String::from))
+            .collect::<Vec<String>>();
+        fs::remove_dir_all(&dir)?;
+
+        assert_eq!(found, vec!["input-bob.txt", "input.txt"]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_answer_record_json() -> Result<()> {
+        let run = TimedRun {
+            parse: Duration::from_millis(1),
+            parts: vec![TimedAnswer {
+                problem: Problem::Two,
+                answer: Answer::from(5963),
+                elapsed: Duration::from_micros(1500),
+            }],
+        };
+
+        let records = run
+            .records(1, "input.txt")
+            .iter()
+            .map(serde_json::to_string)
+            .collect::<Result<Vec<String>, _>>()?;
+
+        assert_eq!(
+            records,
+            vec![r#"{"day":1,"part":2,"answer":5963,"duration_ms":1.5,"input":"input.txt"}"#]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_format_answer_table() {
+        let rows = vec![
+            (String::from("input.txt"), Ok(vec![Answer::from(1043)])),
+            (String::from("input-bob.txt"), Ok(vec![Answer::from(7)])),
+        ];
 
-    match args.problem {
-        Problem::One => problem_one(input),
-        Problem::Two => problem_two(input),
+        assert_eq!(
+            format_answer_table(&[Problem::One], &rows),
+            "input         | part one\ninput.txt     | 1043\ninput-bob.txt | 7"
+        );
     }
 }
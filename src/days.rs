@@ -0,0 +1,24 @@
+use crate::core::Registry;
+
+pub mod day_1;
+pub mod day_2;
+pub mod day_3;
+pub mod day_4;
+pub mod day_5;
+pub mod day_6;
+pub mod day_7;
+
+pub fn registry() -> Registry {
+    let mut registry = Registry::new();
+
+    registry
+        .register(day_1::Day1::default())
+        .register(day_2::Day2)
+        .register(day_3::Day3)
+        .register(day_4::Day4)
+        .register(day_5::Day5)
+        .register(day_6::Day6)
+        .register(day_7::Day7);
+
+    registry
+}
@@ -0,0 +1,1050 @@
+use crate::core::*;
+use crate::parse;
+use anyhow::{Result, anyhow};
+use clap::ValueEnum;
+use serde::Serialize;
+use std::fmt::{Display, Formatter};
+use std::io::BufRead;
+
+#[derive(Debug)]
+struct Safe {
+    size: usize,
+    dial_state: usize,
+    zero_count: usize,
+    zero_crosses: usize,
+    trace: Option<Vec<RotationEvent>>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Direction {
+    Left(usize),
+    Right(usize),
+}
+
+impl Display for Direction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Direction::Left(count) => write!(f, "L{count}"),
+            Direction::Right(count) => write!(f, "R{count}"),
+        }
+    }
+}
+
+/// What one rotation did to the dial, for comparing runs rotation by rotation.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct RotationEvent {
+    pub index: usize,
+    pub instruction: String,
+    pub start: usize,
+    pub end: usize,
+    /// Times the dial passed zero before stopping, not counting where it stopped.
+    pub zeros_passed: usize,
+    pub landed_on_zero: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum TraceFormat {
+    /// A header row then one row per rotation
+    Csv,
+    /// One JSON object per rotation, one per line
+    Json,
+}
+
+impl TraceFormat {
+    pub fn render(&self, events: &[RotationEvent]) -> Result<String> {
+        let lines = match self {
+            TraceFormat::Csv => std::iter::once(String::from(
+                "index,instruction,start,end,zeros_passed,landed_on_zero",
+            ))
+            .chain(events.iter().map(|event| {
+                format!(
+                    "{},{},{},{},{},{}",
+                    event.index,
+                    event.instruction,
+                    event.start,
+                    event.end,
+                    event.zeros_passed,
+                    event.landed_on_zero
+                )
+            }))
+            .collect::<Vec<String>>(),
+            TraceFormat::Json => events
+                .iter()
+                .map(serde_json::to_string)
+                .collect::<serde_json::Result<Vec<String>>>()?,
+        };
+
+        Ok(lines.join("\n") + "\n")
+    }
+}
+
+impl TryFrom<&str> for Direction {
+    type Error = ParseError;
+
+    fn try_from(value: &str) -> std::result::Result<Self, Self::Error> {
+        let rotation = parse::keyed(
+            [
+                ("L", Direction::Left as fn(usize) -> Direction),
+                ("R", Direction::Right),
+            ],
+            parse::integer(),
+        );
+
+        parse::map(rotation, |(direction, count)| direction(count))(value)
+    }
+}
+
+impl Direction {
+    fn parse(input: &str) -> Result<Vec<Direction>> {
+        Ok(parse::lines(|line| Direction::try_from(line))(input)?)
+    }
+}
+
+/// A rotation count of any length, read digit by digit as whole turns of a dial with `size`
+/// positions plus the clicks left over.
+fn turns_and_rest(size: usize) -> impl parse::Parser<(u128, usize)> {
+    move |input: &str| {
+        let text = input.trim();
+        if text.is_empty() || !text.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(ParseError::new(text, "an integer").within(input, text));
+        }
+
+        let (mut turns, mut rest) = (0u128, 0u128);
+        for digit in text.bytes().map(|b| u128::from(b - b'0')) {
+            let clicks = rest * 10 + digit;
+            turns = turns
+                .checked_mul(10)
+                .and_then(|turns| turns.checked_add(clicks / size as u128))
+                .ok_or_else(|| {
+                    ParseError::new(text, "a rotation of fewer than 2^128 turns")
+                        .within(input, text)
+                })?;
+            rest = clicks % size as u128;
+        }
+
+        Ok((turns, rest as usize))
+    }
+}
+
+/// Zero counters wide enough for anything [`Day1::stream`] can read.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct WideTallies {
+    pub zero_count: u128,
+    pub zero_crosses: u128,
+}
+
+impl WideTallies {
+    pub fn part(&self, problem: Problem) -> Result<u128> {
+        match problem {
+            Problem::One => Ok(self.zero_count),
+            Problem::Two => self
+                .zero_count
+                .checked_add(self.zero_crosses)
+                .ok_or_else(|| anyhow!("more than 2^128 clicks onto zero")),
+        }
+    }
+}
+
+impl Safe {
+    /// A dial numbered `0..size` pointing at `dial_state`.
+    pub fn new(size: usize, dial_state: usize) -> Self {
+        Self {
+            size,
+            dial_state,
+            zero_count: 0,
+            zero_crosses: 0,
+            trace: None,
+        }
+    }
+
+    /// Like [`Safe::new`], also keeping a [`RotationEvent`] for every rotation.
+    pub fn traced(size: usize, dial_state: usize) -> Self {
+        Self {
+            trace: Some(vec![]),
+            ..Self::new(size, dial_state)
+        }
+    }
+
+    pub fn rotate(&mut self, direction: &Direction) {
+        let start = self.dial_state;
+        let crosses = self.turn(direction);
+
+        self.zero_crosses += crosses;
+
+        if self.dial_state == 0 {
+            self.zero_count += 1;
+        }
+
+        if let Some(trace) = &mut self.trace {
+            trace.push(RotationEvent {
+                index: trace.len(),
+                instruction: direction.to_string(),
+                start,
+                end: self.dial_state,
+                zeros_passed: crosses,
+                landed_on_zero: self.dial_state == 0,
+            });
+        }
+    }
+
+    /// Moves the dial without counting anything, returning how many times it passed zero.
+    /// Positions are worked out in `i128`, so no `usize` rotation can overflow them.
+    fn turn(&mut self, direction: &Direction) -> usize {
+        let result = match direction {
+            Direction::Left(count) => self.dial_state as i128 - *count as i128,
+            Direction::Right(count) => self.dial_state as i128 + *count as i128,
+        };
+
+        let (wrapped_result, crosses) = self.wrap(result);
+        self.dial_state = wrapped_result;
+
+        crosses
+    }
+
+    /// Where the dial ends after moving to the unwrapped `input`, and how many times it passes
+    /// zero on the way, not counting the position it ends on.
+    fn wrap(&self, input: i128) -> (usize, usize) {
+        let wrapped = input.rem_euclid(self.size as i128);
+        let idial_state = self.dial_state as i128;
+
+        let crosses = if input < idial_state {
+            hits(input + 1, idial_state - 1, 0, self.size)
+        } else {
+            hits(idial_state + 1, input - 1, 0, self.size)
+        };
+
+        (wrapped as usize, crosses)
+    }
+
+    /// How many clicks of `direction`, starting from the current position, land on `target`.
+    pub fn touches(&self, direction: &Direction, target: usize) -> usize {
+        let dial = self.dial_state as i128;
+
+        match direction {
+            Direction::Left(count) => hits(dial - *count as i128, dial - 1, target, self.size),
+            Direction::Right(count) => hits(dial + 1, dial + *count as i128, target, self.size),
+        }
+    }
+}
+
+/// How many of the unwrapped positions `lo..=hi` sit on `target` of a dial with `size`
+/// positions, counted without stepping.
+fn hits(lo: i128, hi: i128, target: usize, size: usize) -> usize {
+    if lo > hi {
+        return 0;
+    }
+
+    let (target, size) = (target as i128, size as i128);
+    ((hi - target).div_euclid(size) - (lo - 1 - target).div_euclid(size)) as usize
+}
+
+/// Rotations that stopped on each position, and clicks that landed on each position, over a
+/// whole instruction list.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DialHistogram {
+    pub stops: Vec<usize>,
+    pub touches: Vec<usize>,
+}
+
+impl DialHistogram {
+    /// The histogram one position at a time, from zero up.
+    pub fn counts(&self) -> impl Iterator<Item = TargetCount> + '_ {
+        self.stops
+            .iter()
+            .zip(&self.touches)
+            .enumerate()
+            .map(|(position, (&stops, &touches))| TargetCount {
+                position,
+                stops,
+                touches,
+            })
+    }
+}
+
+/// The stops and touches of one target position.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TargetCount {
+    pub position: usize,
+    pub stops: usize,
+    pub touches: usize,
+}
+
+/// A safe's two zero counters, as accumulated over some run of rotations.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Tallies {
+    pub zero_count: usize,
+    pub zero_crosses: usize,
+}
+
+impl std::ops::Add for Tallies {
+    type Output = Tallies;
+
+    fn add(self, other: Tallies) -> Tallies {
+        Tallies {
+            zero_count: self.zero_count + other.zero_count,
+            zero_crosses: self.zero_crosses + other.zero_crosses,
+        }
+    }
+}
+
+impl std::ops::Sub for Tallies {
+    type Output = Tallies;
+
+    fn sub(self, other: Tallies) -> Tallies {
+        Tallies {
+            zero_count: self.zero_count - other.zero_count,
+            zero_crosses: self.zero_crosses - other.zero_crosses,
+        }
+    }
+}
+
+/// Which count an inverse search is aiming for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum Tally {
+    /// Rotations that end on zero (part one)
+    ZeroCount,
+    /// Passes over zero, not counting where a rotation ends
+    ZeroCrosses,
+    /// Every click onto zero (part two)
+    Clicks,
+}
+
+impl Tally {
+    pub fn of(&self, tallies: Tallies) -> usize {
+        match self {
+            Tally::ZeroCount => tallies.zero_count,
+            Tally::ZeroCrosses => tallies.zero_crosses,
+            Tally::Clicks => tallies.zero_count + tallies.zero_crosses,
+        }
+    }
+
+    /// How much one extra full turn in a rotation adds to this count.
+    fn per_turn(&self) -> usize {
+        match self {
+            Tally::ZeroCount => 0,
+            Tally::ZeroCrosses | Tally::Clicks => 1,
+        }
+    }
+}
+
+/// Replacing the instruction at `index` with `replacement`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Edit {
+    pub index: usize,
+    pub original: Direction,
+    pub replacement: Direction,
+}
+
+impl Display for Edit {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "rotation {}: {} -> {}",
+            self.index, self.original, self.replacement
+        )
+    }
+}
+
+pub const DIAL_SIZE: usize = 100;
+pub const DIAL_START: usize = 50;
+
+/// The safe puzzle for a dial of `size` positions that starts at `start`.
+pub struct Day1 {
+    size: usize,
+    start: usize,
+}
+
+impl Day1 {
+    pub fn new(size: usize, start: usize) -> Result<Self> {
+        if size == 0 {
+            return Err(anyhow!("a dial needs at least one position"));
+        }
+        if start >= size {
+            return Err(anyhow!("a dial of size {size} has no position {start}"));
+        }
+
+        Ok(Self { size, start })
+    }
+
+    fn open(&self, input: &[Direction]) -> Safe {
+        let mut safe = Safe::new(self.size, self.start);
+        input.iter().for_each(|direction| safe.rotate(direction));
+
+        safe
+    }
+
+    /// Every rotation of the dial, in order.
+    pub fn trace(&self, input: &[Direction]) -> Vec<RotationEvent> {
+        let mut safe = Safe::traced(self.size, self.start);
+        input.iter().for_each(|direction| safe.rotate(direction));
+
+        safe.trace.unwrap_or_default()
+    }
+
+    /// Tallies every rotation in constant time: each full turn touches every position once,
+    /// and the remaining clicks touch one contiguous run of positions.
+    pub fn histogram(&self, input: &[Direction]) -> DialHistogram {
+        let size = self.size;
+        let mut safe = Safe::new(size, self.start);
+        let mut stops = vec![0; size];
+        let mut laps = 0;
+        let mut runs = vec![0isize; size + 1];
+
+        for direction in input {
+            let dial = safe.dial_state;
+            let (count, first) = match direction {
+                Direction::Left(count) => (*count, (dial + size - count % size) % size),
+                Direction::Right(count) => (*count, (dial + 1) % size),
+            };
+            let rest = count % size;
+            laps += count / size;
+
+            // Mark the run `first..first + rest` on the dial, split in two where it wraps.
+            runs[first] += 1;
+            if first + rest <= size {
+                runs[first + rest] -= 1;
+            } else {
+                runs[size] -= 1;
+                runs[0] += 1;
+                runs[first + rest - size] -= 1;
+            }
+
+            safe.rotate(direction);
+            stops[safe.dial_state] += 1;
+        }
+
+        let touches = runs[..size]
+            .iter()
+            .scan(0isize, |covered, delta| {
+                *covered += delta;
+                Some(laps + *covered as usize)
+            })
+            .collect();
+
+        DialHistogram { stops, touches }
+    }
+
+    /// Stops on and touches of each of `targets`, in the order given with repeats dropped,
+    /// counted rotation by rotation in closed form.
+    pub fn count_targets(
+        &self,
+        input: &[Direction],
+        targets: &[usize],
+    ) -> Result<Vec<TargetCount>> {
+        if let Some(position) = targets.iter().find(|&&position| position >= self.size) {
+            return Err(anyhow!(
+                "a dial of size {} has no position {position}",
+                self.size
+            ));
+        }
+
+        let mut counts: Vec<TargetCount> = vec![];
+        for &position in targets {
+            if counts.iter().all(|count| count.position != position) {
+                counts.push(TargetCount {
+                    position,
+                    stops: 0,
+                    touches: 0,
+                });
+            }
+        }
+        let mut safe = Safe::new(self.size, self.start);
+
+        for direction in input {
+            for count in &mut counts {
+                count.touches += safe.touches(direction, count.position);
+            }
+            safe.rotate(direction);
+            for count in &mut counts {
+                count.stops += usize::from(safe.dial_state == count.position);
+            }
+        }
+
+        Ok(counts)
+    }
+
+    /// Applies each rotation as it is read from `reader`, so memory stays constant however long
+    /// the input is. Counts of any length are taken apart into whole turns, which only add to
+    /// `zero_crosses`, and a rotation of at most one turn, which goes through [`Safe`].
+    pub fn stream(&self, mut reader: impl BufRead) -> Result<WideTallies> {
+        let rotation = parse::keyed(
+            [
+                ("L", Direction::Left as fn(usize) -> Direction),
+                ("R", Direction::Right),
+            ],
+            turns_and_rest(self.size),
+        );
+        let mut safe = Safe::new(self.size, self.start);
+        let mut tallies = WideTallies::default();
+        let mut line = String::new();
+
+        for number in 1.. {
+            line.clear();
+            if reader.read_line(&mut line)? == 0 {
+                break;
+            }
+
+            let (direction, (turns, rest)) = rotation(line.trim_end_matches(['\n', '\r']))
+                .map_err(|error| error.at(number, 1).for_day(Self::DAY))?;
+            // Keep a rotation of whole turns as one full turn so it still ends where it began.
+            let (turns, rest) = match (turns, rest) {
+                (0, rest) => (0, rest),
+                (turns, 0) => (turns - 1, self.size),
+                (turns, rest) => (turns, rest),
+            };
+
+            let crosses = safe.turn(&direction(rest));
+            tallies.zero_crosses = tallies
+                .zero_crosses
+                .checked_add(crosses as u128)
+                .and_then(|total| total.checked_add(turns))
+                .ok_or_else(|| anyhow!("line {number}: more than 2^128 passes over zero"))?;
+            if safe.dial_state == 0 {
+                tallies.zero_count += 1;
+            }
+        }
+
+        Ok(tallies)
+    }
+
+    fn tallies_of(&self, dial: usize, direction: &Direction) -> (usize, Tallies) {
+        let mut safe = Safe::new(self.size, dial);
+        safe.rotate(direction);
+
+        (
+            safe.dial_state,
+            Tallies {
+                zero_count: safe.zero_count,
+                zero_crosses: safe.zero_crosses,
+            },
+        )
+    }
+
+    /// How far `direction` moves the dial, as a number of positions to the right.
+    fn shift(&self, direction: &Direction) -> usize {
+        match direction {
+            Direction::Left(count) => (self.size - count % self.size) % self.size,
+            Direction::Right(count) => count % self.size,
+        }
+    }
+
+    /// Turns `suffix`, what the rotations after `direction` add from each dial, into what
+    /// `direction` and those rotations add. A rotation moves every dial by the same amount, so
+    /// this is a shift of the whole row plus the rotation's own tallies.
+    fn fold(&self, suffix: &mut [Tallies], direction: &Direction) {
+        suffix.rotate_left(self.shift(direction));
+        for (dial, tallies) in suffix.iter_mut().enumerate() {
+            *tallies = self.tallies_of(dial, direction).1 + *tallies;
+        }
+    }
+
+    /// Undoes [`Day1::fold`], dropping `direction` from the front of `suffix`.
+    fn unfold(&self, suffix: &mut [Tallies], direction: &Direction) {
+        for (dial, tallies) in suffix.iter_mut().enumerate() {
+            *tallies = *tallies - self.tallies_of(dial, direction).1;
+        }
+        suffix.rotate_right(self.shift(direction));
+    }
+
+    /// What all of `input` adds from each dial, folded from the last rotation back so only one
+    /// row of the dial is ever held. Fails when that row does not fit in memory.
+    fn suffix(&self, input: &[Direction]) -> Result<Vec<Tallies>> {
+        let mut suffix = Vec::new();
+        suffix.try_reserve_exact(self.size).map_err(|_| {
+            anyhow!(
+                "a dial of size {} is too big to search: its tallies do not fit in memory",
+                self.size
+            )
+        })?;
+        suffix.resize(self.size, Tallies::default());
+
+        for direction in input.iter().rev() {
+            self.fold(&mut suffix, direction);
+        }
+
+        Ok(suffix)
+    }
+
+    /// Every start position from which `input` reaches `goal` for `tally`.
+    pub fn starts_for(&self, input: &[Direction], tally: Tally, goal: usize) -> Result<Vec<usize>> {
+        let suffix = self.suffix(input)?;
+
+        Ok((0..self.size)
+            .filter(|&start| tally.of(suffix[start]) == goal)
+            .collect())
+    }
+
+    /// Every change to a single instruction that makes `input` reach `goal` for `tally` from
+    /// this day's start.
+    ///
+    /// A rotation's end position repeats every full turn, so each direction and end position
+    /// is tried once and the number of extra turns needed is solved for. When extra turns
+    /// don't change the count, only the shortest such rotation is returned.
+    pub fn edits_for(&self, input: &[Direction], tally: Tally, goal: usize) -> Result<Vec<Edit>> {
+        let mut suffix = self.suffix(input)?;
+        let mut edits = vec![];
+        let mut safe = Safe::new(self.size, self.start);
+
+        for (index, original) in input.iter().enumerate() {
+            let before = Tallies {
+                zero_count: safe.zero_count,
+                zero_crosses: safe.zero_crosses,
+            };
+            self.unfold(&mut suffix, original);
+
+            for rest in 1..=self.size {
+                for shortest in [Direction::Left(rest), Direction::Right(rest)] {
+                    let (end, tallies) = self.tallies_of(safe.dial_state, &shortest);
+                    let reached = tally.of(before + tallies + suffix[end]);
+
+                    let count = if tally.per_turn() == 0 {
+                        (reached == goal).then_some(rest)
+                    } else {
+                        goal.checked_sub(reached)
+                            .and_then(|turns| turns.checked_mul(self.size))
+                            .and_then(|extra| extra.checked_add(rest))
+                    };
+                    let replacement = match (count, shortest) {
+                        (Some(count), Direction::Left(_)) => Direction::Left(count),
+                        (Some(count), Direction::Right(_)) => Direction::Right(count),
+                        (None, _) => continue,
+                    };
+
+                    if replacement != *original {
+                        edits.push(Edit {
+                            index,
+                            original: *original,
+                            replacement,
+                        });
+                    }
+                }
+            }
+
+            safe.rotate(original);
+        }
+
+        Ok(edits)
+    }
+}
+
+impl Default for Day1 {
+    fn default() -> Self {
+        Self {
+            size: DIAL_SIZE,
+            start: DIAL_START,
+        }
+    }
+}
+
+impl Solution for Day1 {
+    const DAY: usize = 1;
+
+    type Input = Vec<Direction>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        Direction::parse(input)
+    }
+
+    fn part_one(&self, input: &Self::Input) -> Result<Answer> {
+        Ok(self.open(input).zero_count.into())
+    }
+
+    fn part_two(&self, input: &Self::Input) -> Result<Answer> {
+        let safe = self.open(input);
+
+        Ok((safe.zero_crosses + safe.zero_count).into())
+    }
+}
+
+#[cfg(test)]
+mod day_1_tests {
+    use super::*;
+    use crate::generators;
+    use proptest::prelude::*;
+
+    const INPUT: &str = "L68
+L30
+R48
+L5
+R60
+L55
+L1
+L99
+R14
+L82";
+
+    crate::example_tests! {
+        Day1::default(), recorded test_recorded_answers;
+        test_problem_one: (INPUT, One, 3),
+        test_problem_two: (INPUT, Two, 6),
+    }
+
+    #[test]
+    fn test_problem_multi_wrap() -> Result<()> {
+        let result = Direction::parse("R1000")?;
+        let mut safe = Safe::new(100, 50);
+
+        result.iter().for_each(|direction| safe.rotate(direction));
+        assert_eq!(safe.zero_crosses, 10);
+        Ok(())
+    }
+
+    #[test]
+    fn test_problem_left_zero() -> Result<()> {
+        let result = Direction::parse("L22")?;
+        let mut safe = Safe::new(100, 22);
+
+        result.iter().for_each(|direction| safe.rotate(direction));
+        assert_eq!(safe.zero_crosses + safe.zero_count, 1);
+        Ok(())
+    }
+
+    #[test]
+    fn test_problem_right_zero() -> Result<()> {
+        let result = Direction::parse("R199")?;
+        let mut safe = Safe::new(100, 1);
+
+        result.iter().for_each(|direction| safe.rotate(direction));
+        assert_eq!(safe.zero_crosses + safe.zero_count, 2);
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_error_location() {
+        let error = Direction::parse("L68\nR4x\n").unwrap_err();
+
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 2: expected an integer, found \"4x\""
+        );
+    }
+
+    /// Turns a dial of `size` positions one click at a time, returning where it ends and how
+    /// many clicks landed on zero.
+    fn click_by_click(size: usize, start: usize, direction: &Direction) -> (usize, usize) {
+        let (step, count) = match direction {
+            Direction::Left(count) => (size - 1, *count),
+            Direction::Right(count) => (1, *count),
+        };
+        let mut dial = start;
+        let mut zeros = 0;
+
+        for _ in 0..count {
+            dial = (dial + step) % size;
+            if dial == 0 {
+                zeros += 1;
+            }
+        }
+
+        (dial, zeros)
+    }
+
+    #[test]
+    fn test_every_rotation_on_small_dials() {
+        for size in 1..=12 {
+            for start in 0..size {
+                for count in 0..=3 * size + 1 {
+                    for direction in [Direction::Left(count), Direction::Right(count)] {
+                        let mut safe = Safe::new(size, start);
+                        safe.rotate(&direction);
+                        let (dial, zeros) = click_by_click(size, start, &direction);
+                        let landed = usize::from(count > 0 && dial == 0);
+
+                        assert_eq!(
+                            safe.dial_state, dial,
+                            "{direction:?} on {size} from {start}"
+                        );
+                        assert_eq!(
+                            safe.zero_crosses + landed,
+                            zeros,
+                            "{direction:?} on {size} from {start}"
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_trace() -> Result<()> {
+        let directions = Direction::parse(INPUT)?;
+        let trace = Day1::default().trace(&directions);
+
+        assert_eq!(
+            trace[0],
+            RotationEvent {
+                index: 0,
+                instruction: String::from("L68"),
+                start: 50,
+                end: 82,
+                zeros_passed: 1,
+                landed_on_zero: false,
+            }
+        );
+        assert_eq!(trace.iter().filter(|event| event.landed_on_zero).count(), 3);
+        assert_eq!(
+            trace
+                .iter()
+                .map(|event| event.zeros_passed + usize::from(event.landed_on_zero))
+                .sum::<usize>(),
+            6
+        );
+
+        let csv = TraceFormat::Csv.render(&trace[..2])?;
+        assert_eq!(
+            csv,
+            "index,instruction,start,end,zeros_passed,landed_on_zero\n0,L68,50,82,1,false\n1,L30,82,52,0,false\n"
+        );
+        let json = TraceFormat::Json.render(&trace[..1])?;
+        assert_eq!(
+            json,
+            "{\"index\":0,\"instruction\":\"L68\",\"start\":50,\"end\":82,\"zeros_passed\":1,\"landed_on_zero\":false}\n"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_count_targets() -> Result<()> {
+        let directions = Direction::parse(INPUT)?;
+        let counts = Day1::default().count_targets(&directions, &[0, 32, 0])?;
+
+        assert_eq!(
+            counts,
+            vec![
+                TargetCount {
+                    position: 0,
+                    stops: 3,
+                    touches: 6
+                },
+                TargetCount {
+                    position: 32,
+                    stops: 1,
+                    touches: 5
+                },
+            ]
+        );
+        assert!(Day1::default().count_targets(&directions, &[100]).is_err());
+
+        let histogram = Day1::default().histogram(&directions);
+        let histogram = histogram.counts().collect::<Vec<TargetCount>>();
+        assert_eq!(histogram.len(), 100);
+        assert_eq!([histogram[0], histogram[32]].to_vec(), counts);
+        Ok(())
+    }
+
+    #[test]
+    fn test_inverse_search() -> Result<()> {
+        let directions = Direction::parse(INPUT)?;
+        let day = Day1::default();
+
+        assert!(
+            day.starts_for(&directions, Tally::ZeroCount, 3)?
+                .contains(&50)
+        );
+        assert!(day.starts_for(&directions, Tally::Clicks, 1000)?.is_empty());
+
+        let edits = day.edits_for(&directions, Tally::Clicks, 7)?;
+        assert!(edits.contains(&Edit {
+            index: 0,
+            original: Direction::Left(68),
+            replacement: Direction::Left(168),
+        }));
+        for edit in edits {
+            let mut edited = directions.clone();
+            edited[edit.index] = edit.replacement;
+            assert_eq!(day.part_two(&edited)?, Answer::from(7), "{edit}");
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_inverse_search_on_large_dials() -> Result<()> {
+        let directions = [Direction::Right(500_000), Direction::Left(1_000_000)];
+        let day = Day1::new(1_000_000, 500_000)?;
+
+        assert_eq!(
+            day.starts_for(&directions, Tally::ZeroCount, 2)?,
+            vec![500_000]
+        );
+        assert_eq!(
+            day.edits_for(&directions, Tally::ZeroCount, 2)?
+                .iter()
+                .map(|edit| (edit.index, edit.replacement))
+                .collect::<Vec<(usize, Direction)>>(),
+            vec![
+                (0, Direction::Left(500_000)),
+                (1, Direction::Right(1_000_000))
+            ]
+        );
+
+        let error = Day1::new(usize::MAX, 0)?
+            .starts_for(&directions, Tally::ZeroCount, 2)
+            .unwrap_err();
+        assert!(error.to_string().contains("too big to search"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_stream() -> Result<()> {
+        let tallies = Day1::default().stream(INPUT.as_bytes())?;
+        assert_eq!(
+            (tallies.part(Problem::One)?, tallies.part(Problem::Two)?),
+            (3, 6)
+        );
+
+        // 10^29 clicks is 10^27 whole turns, far past what a usize can count.
+        let huge = format!("R1{}\nL{}\n", "0".repeat(29), "9".repeat(30));
+        let tallies = Day1::default().stream(huge.as_bytes())?;
+        assert_eq!(
+            tallies.zero_crosses,
+            10u128.pow(27) + (10u128.pow(30) - 1 - 50) / 100 + 1
+        );
+        assert_eq!(tallies.zero_count, 0);
+
+        let error = Day1::default().stream("L5\nR1x\n".as_bytes()).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "day 1, line 2, column 2: expected an integer, found \"1x\""
+        );
+        assert!(
+            Day1::default()
+                .stream(format!("R{}", "9".repeat(45)).as_bytes())
+                .is_err()
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_dial_options() -> Result<()> {
+        let directions = Direction::parse(INPUT)?;
+
+        assert_eq!(Day1::new(100, 50)?.part_two(&directions)?, Answer::from(6));
+        // Every click of a one-position dial lands on zero.
+        assert_eq!(Day1::new(1, 0)?.part_two(&directions)?, Answer::from(462));
+        assert!(Day1::new(0, 0).is_err());
+        assert!(Day1::new(10, 10).is_err());
+        Ok(())
+    }
+
+    proptest! {
+        #[test]
+        fn rotation_matches_clicks(start in 0usize..100, left in prop::bool::ANY, count in 1usize..1000) {
+            let direction = if left { Direction::Left(count) } else { Direction::Right(count) };
+            let mut safe = Safe::new(100, start);
+            safe.rotate(&direction);
+            let (dial, zeros) = click_by_click(100, start, &direction);
+
+            prop_assert_eq!(safe.dial_state, dial);
+            prop_assert_eq!(safe.zero_crosses + safe.zero_count, zeros);
+        }
+
+        #[test]
+        fn histogram_matches_clicks(
+            (size, start) in (1usize..12).prop_flat_map(|size| (Just(size), 0..size)),
+            rotations in prop::collection::vec((prop::bool::ANY, 0usize..40), 0..20),
+        ) {
+            let directions = rotations
+                .into_iter()
+                .map(|(left, count)| if left { Direction::Left(count) } else { Direction::Right(count) })
+                .collect::<Vec<Direction>>();
+            let day = Day1::new(size, start).map_err(|e| TestCaseError::fail(e.to_string()))?;
+
+            let (mut stops, mut touches) = (vec![0; size], vec![0; size]);
+            let mut dial = start;
+            for direction in &directions {
+                let (step, count) = match direction {
+                    Direction::Left(count) => (size - 1, *count),
+                    Direction::Right(count) => (1, *count),
+                };
+                for _ in 0..count {
+                    dial = (dial + step) % size;
+                    touches[dial] += 1;
+                }
+                stops[dial] += 1;
+            }
+
+            let positions = (0..size).collect::<Vec<usize>>();
+            let counts = day
+                .count_targets(&directions, &positions)
+                .map_err(|e| TestCaseError::fail(e.to_string()))?;
+            prop_assert_eq!(
+                counts.iter().map(|count| (count.stops, count.touches)).collect::<Vec<_>>(),
+                stops.iter().copied().zip(touches.iter().copied()).collect::<Vec<_>>()
+            );
+            prop_assert_eq!(day.histogram(&directions), DialHistogram { stops, touches });
+        }
+
+        #[test]
+        fn inverse_search_matches_brute_force(
+            (size, start) in (1usize..8).prop_flat_map(|size| (Just(size), 0..size)),
+            rotations in prop::collection::vec((prop::bool::ANY, 1usize..20), 1..6),
+            tally in prop_oneof![Just(Tally::ZeroCount), Just(Tally::ZeroCrosses), Just(Tally::Clicks)],
+            goal in 0usize..6,
+        ) {
+            let directions = rotations
+                .into_iter()
+                .map(|(left, count)| if left { Direction::Left(count) } else { Direction::Right(count) })
+                .collect::<Vec<Direction>>();
+            let day = Day1::new(size, start).map_err(|e| TestCaseError::fail(e.to_string()))?;
+            let reaches = |start: usize, directions: &[Direction]| {
+                let safe = Day1 { size, start }.open(directions);
+                tally.of(Tallies { zero_count: safe.zero_count, zero_crosses: safe.zero_crosses })
+            };
+
+            let starts = (0..size).filter(|&start| reaches(start, &directions) == goal).collect::<Vec<usize>>();
+            let found = day.starts_for(&directions, tally, goal).map_err(|e| TestCaseError::fail(e.to_string()))?;
+            prop_assert_eq!(found, starts);
+
+            // Longer rotations only matter when extra turns change the count, and then at most
+            // `goal` extra turns can help.
+            let longest = if tally.per_turn() == 0 { size } else { (goal + 1) * size };
+            let mut expected = vec![];
+            for index in 0..directions.len() {
+                for count in 1..=longest {
+                    for replacement in [Direction::Left(count), Direction::Right(count)] {
+                        let mut edited = directions.clone();
+                        edited[index] = replacement;
+                        if replacement != directions[index] && reaches(start, &edited) == goal {
+                            expected.push((index, replacement));
+                        }
+                    }
+                }
+            }
+            let mut found = day
+                .edits_for(&directions, tally, goal)
+                .map_err(|e| TestCaseError::fail(e.to_string()))?
+                .into_iter()
+                .map(|edit| (edit.index, edit.replacement))
+                .collect::<Vec<(usize, Direction)>>();
+            let order = |&(index, direction): &(usize, Direction)| (index, direction.to_string());
+            expected.sort_by_key(order);
+            found.sort_by_key(order);
+            prop_assert_eq!(found, expected);
+        }
+
+        #[test]
+        fn stream_matches_solution(input in generators::rotations()) {
+            let tallies = Day1::default()
+                .stream(input.as_bytes())
+                .map_err(|e| TestCaseError::fail(e.to_string()))?;
+            let (one, two) = generators::solve(&Day1::default(), &input)?;
+
+            prop_assert_eq!(Answer::from(tallies.zero_count as usize), one);
+            prop_assert_eq!(Answer::from((tallies.zero_count + tallies.zero_crosses) as usize), two);
+        }
+
+        #[test]
+        fn solution_matches_clicks(input in generators::rotations()) {
+            let directions = Direction::parse(&input).map_err(|e| TestCaseError::fail(e.to_string()))?;
+            let (mut dial, mut stops, mut clicks) = (50, 0, 0);
+            for direction in &directions {
+                let (next, zeros) = click_by_click(100, dial, direction);
+                dial = next;
+                stops += usize::from(dial == 0);
+                clicks += zeros;
+            }
+
+            prop_assert_eq!(generators::solve(&Day1::default(), &input)?, (stops.into(), clicks.into()));
+        }
+    }
+}
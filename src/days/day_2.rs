@@ -0,0 +1,120 @@
+use crate::core::*;
+use crate::interval::Interval;
+use crate::parse;
+use anyhow::Result;
+
+fn invalid_reflections(interval: &Interval) -> impl Iterator<Item = usize> {
+    interval.iter().filter(|x| number_is_reflected(*x))
+}
+
+fn number_is_reflected(num: usize) -> bool {
+    let Some(digits) = num.checked_ilog10().map(|log| log + 1) else {
+        return false;
+    };
+    if !digits.is_multiple_of(2) {
+        return false;
+    };
+    let half_base = usize::pow(10, digits / 2);
+    let left = num / half_base;
+    let right = num - (left * half_base);
+
+    left == right
+}
+
+fn invalid_repititions(interval: &Interval) -> impl Iterator<Item = usize> {
+    interval
+        .iter()
+        .filter(|x| number_contains_repeated_subsequence(*x))
+}
+
+fn number_contains_repeated_subsequence(num: usize) -> bool {
+    let Some(digits) = num.checked_ilog10().map(|log| log + 1) else {
+        return false;
+    };
+
+    // The id is a repeat when every `len`-digit chunk, read from the right, equals the last.
+    (1..digits)
+        .filter(|len| digits.is_multiple_of(*len))
+        .any(|len| {
+            let base = usize::pow(10, len);
+            let pattern = num % base;
+            let mut rest = num / base;
+            while rest > 0 {
+                if rest % base != pattern {
+                    return false;
+                }
+                rest /= base;
+            }
+
+            true
+        })
+}
+
+/// The ranges exactly as listed; an id in two overlapping ranges counts once for each.
+fn parse_input_into_range(input: &str) -> Result<Vec<Interval>> {
+    Ok(parse::separated(",", |raw| Interval::try_from(raw))(input)?)
+}
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    const DAY: usize = 2;
+
+    type Input = Vec<Interval>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        parse_input_into_range(input)
+    }
+
+    fn part_one(&self, input: &Self::Input) -> Result<Answer> {
+        let result = input.iter().flat_map(invalid_reflections).sum::<usize>();
+
+        Ok(result.into())
+    }
+
+    fn part_two(&self, input: &Self::Input) -> Result<Answer> {
+        let result = input.iter().flat_map(invalid_repititions).sum::<usize>();
+
+        Ok(result.into())
+    }
+}
+
+#[cfg(test)]
+mod day_2_tests {
+    use super::*;
+    use crate::generators;
+    use proptest::prelude::*;
+
+    const INPUT: &str = "11-22,95-115,998-1012,1188511880-1188511890,222220-222224,1698522-1698528,446443-446449,38593856-38593862,565653-565659,824824821-824824827,2121212118-2121212124";
+
+    crate::example_tests! {
+        Day2, recorded test_recorded_answers;
+        test_problem_one: (INPUT, One, 1227775554),
+        test_problem_two: (INPUT, Two, 4174379265),
+        test_overlapping_ranges_one: ("11-22,11-22", One, 66),
+        test_overlapping_ranges_two: ("11-22,11-22", Two, 66),
+    }
+
+    /// Whether the id's digits are `times` copies of some shorter run of digits.
+    fn is_repeated(id: usize, times: impl Fn(usize) -> bool) -> bool {
+        let digits = id.to_string();
+        (2..=digits.len())
+            .filter(|&n| digits.len().is_multiple_of(n) && times(n))
+            .any(|n| digits == digits[..digits.len() / n].repeat(n))
+    }
+
+    proptest! {
+        #[test]
+        fn solution_matches_naive(input in generators::id_ranges()) {
+            let ids = input
+                .split(',')
+                .filter_map(|range| range.split_once('-'))
+                .flat_map(|(start, end)| start.parse::<usize>().unwrap()..=end.parse().unwrap())
+                .collect::<Vec<usize>>();
+            let reflected = ids.iter().filter(|&&id| is_repeated(id, |n| n == 2)).sum::<usize>();
+            let repeated = ids.iter().filter(|&&id| is_repeated(id, |_| true)).sum::<usize>();
+
+            prop_assert_eq!(generators::solve(&Day2, &input)?, (reflected.into(), repeated.into()));
+        }
+    }
+}
@@ -0,0 +1,139 @@
+use crate::core::*;
+use crate::parse;
+use anyhow::{Result, anyhow};
+use std::cmp::min;
+
+pub struct Bank(Vec<u32>);
+
+impl TryFrom<&str> for Bank {
+    type Error = ParseError;
+
+    fn try_from(value: &str) -> std::result::Result<Self, Self::Error> {
+        parse::map(parse::digits(), Bank)(value)
+    }
+}
+
+impl Bank {
+    pub fn turn_on(&self, k: usize) -> Result<usize> {
+        Bank::turn_on_inner(&self.0, k)
+    }
+
+    pub fn turn_on_inner(bank: &[u32], k: usize) -> Result<usize> {
+        if k == 0 {
+            return Ok(0);
+        }
+
+        let possible_base = usize::pow(10, k as u32 - 1);
+
+        let (next, next_idx) = bank
+            .iter()
+            .enumerate()
+            .map(|(idx, n)| {
+                let exp = bank.len() - idx - 1;
+                let max_exp = usize::min(exp, k + 1);
+                let real_base = usize::pow(10, max_exp as u32);
+                (
+                    idx,
+                    min((*n as usize) * real_base, (*n as usize) * possible_base),
+                )
+            })
+            .fold(None, |acc, (idx, quantity)| match acc {
+                None => Some((quantity, idx)),
+                Some((last_highest_quantity, _)) => {
+                    if last_highest_quantity >= quantity {
+                        acc
+                    } else {
+                        Some((quantity, idx))
+                    }
+                }
+            })
+            .ok_or_else(|| anyhow!("ran out of batteries with {k} still to turn on"))?;
+
+        let next_bank = bank
+            .iter()
+            .skip(next_idx + 1)
+            .copied()
+            .collect::<Vec<u32>>();
+
+        Ok(next + Bank::turn_on_inner(&next_bank, k - 1)?)
+    }
+}
+
+fn parse_into_banks(input: &str) -> Result<Vec<Bank>> {
+    Ok(parse::lines(|line| Bank::try_from(line))(input)?)
+}
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    const DAY: usize = 3;
+
+    type Input = Vec<Bank>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        parse_into_banks(input)
+    }
+
+    fn part_one(&self, input: &Self::Input) -> Result<Answer> {
+        Ok(input
+            .iter()
+            .map(|bank| bank.turn_on(2))
+            .sum::<Result<usize>>()?
+            .into())
+    }
+
+    fn part_two(&self, input: &Self::Input) -> Result<Answer> {
+        Ok(input
+            .iter()
+            .map(|bank| bank.turn_on(12))
+            .sum::<Result<usize>>()?
+            .into())
+    }
+}
+
+#[cfg(test)]
+mod day_3_tests {
+    use super::*;
+    use crate::generators;
+    use itertools::Itertools;
+    use proptest::prelude::*;
+
+    const INPUT: &str = "987654321111111
+811111111111119
+234234234234278
+818181911112111";
+
+    crate::example_tests! {
+        Day3, recorded test_recorded_answers;
+        test_problem_one: (INPUT, One, 357),
+        test_problem_two: (INPUT, Two, 3121910778619),
+    }
+
+    #[test]
+    fn test_short_bank_is_an_error() -> Result<()> {
+        let banks = parse_into_banks("987")?;
+
+        assert_eq!(banks[0].turn_on(2)?, 98);
+        assert!(banks[0].turn_on(12).is_err());
+        Ok(())
+    }
+
+    /// Tries every choice of `k` batteries in order and keeps the largest joltage.
+    fn best_joltage(bank: &str, k: usize) -> usize {
+        bank.chars()
+            .combinations(k)
+            .map(|batteries| batteries.into_iter().collect::<String>().parse().unwrap())
+            .max()
+            .unwrap_or(0)
+    }
+
+    proptest! {
+        #[test]
+        fn solution_matches_every_choice(input in generators::banks()) {
+            let two = input.lines().map(|bank| best_joltage(bank, 2)).sum::<usize>();
+            let twelve = input.lines().map(|bank| best_joltage(bank, 12)).sum::<usize>();
+
+            prop_assert_eq!(generators::solve(&Day3, &input)?, (two.into(), twelve.into()));
+        }
+    }
+}
@@ -0,0 +1,204 @@
+use crate::core::*;
+use crate::grid::{Grid, Position};
+use crate::parse;
+use crate::viz::{Frame, Recorder};
+use anyhow::Result;
+use std::fmt::{Display, Formatter};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Cell {
+    Paper,
+    Empty,
+}
+
+impl Display for Cell {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Cell::Paper => write!(f, "@"),
+            Cell::Empty => write!(f, "."),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Map {
+    grid: Grid<Cell>,
+    paper_positions: Vec<Position>,
+}
+
+impl TryFrom<&str> for Map {
+    type Error = ParseError;
+
+    fn try_from(value: &str) -> std::result::Result<Self, Self::Error> {
+        let grid = parse::grid(parse::one_of([('@', Cell::Paper), ('.', Cell::Empty)]))(value)?;
+        let paper_positions = grid
+            .iter()
+            .filter(|(_, cell)| **cell == Cell::Paper)
+            .map(|(position, _)| position)
+            .collect();
+
+        Ok(Map {
+            grid,
+            paper_positions,
+        })
+    }
+}
+
+impl Map {
+    pub fn count_neighbors(&self, position: Position) -> usize {
+        self.grid
+            .neighbors8(position)
+            .filter(|neighbor| self.grid[*neighbor] == Cell::Paper)
+            .count()
+    }
+
+    pub fn is_roll_accessible(&self, position: &Position) -> bool {
+        self.count_neighbors(*position) < 4
+    }
+
+    pub fn count_accessible_rolls(&self) -> usize {
+        self.paper_positions
+            .iter()
+            .filter(|pos| self.is_roll_accessible(pos))
+            .count()
+    }
+
+    pub fn exaust_all_accessible_rolls(&mut self, recorder: &mut Recorder) -> usize {
+        let mut removed = 0;
+        recorder.record(|| Frame::new(&self.grid, "start"));
+
+        for round in 1.. {
+            let candidates = self
+                .paper_positions
+                .iter()
+                .filter(|pos| self.is_roll_accessible(pos))
+                .copied()
+                .collect::<Vec<Position>>();
+
+            if candidates.is_empty() {
+                break;
+            }
+            recorder.record(|| {
+                Frame::new(
+                    &self.grid,
+                    format!("round {round}: removing {}", candidates.len()),
+                )
+                .highlight(candidates.iter().copied())
+            });
+
+            for candidate in &candidates {
+                self.grid[*candidate] = Cell::Empty;
+            }
+            self.paper_positions
+                .retain(|pos| self.grid[*pos] == Cell::Paper);
+
+            removed += candidates.len();
+        }
+
+        recorder.record(|| Frame::new(&self.grid, format!("{removed} removed")));
+        removed
+    }
+}
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    const DAY: usize = 4;
+
+    type Input = Map;
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        Ok(Map::try_from(input)?)
+    }
+
+    fn part_one(&self, input: &Self::Input) -> Result<Answer> {
+        Ok(input.count_accessible_rolls().into())
+    }
+
+    fn part_two(&self, input: &Self::Input) -> Result<Answer> {
+        let mut map = input.clone();
+        Ok(map.exaust_all_accessible_rolls(&mut Recorder::off()).into())
+    }
+
+    fn visualize(&self, input: &Self::Input, recorder: &mut Recorder) -> Result<()> {
+        input.clone().exaust_all_accessible_rolls(recorder);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod day_4_tests {
+    use super::*;
+    use crate::generators;
+    use proptest::prelude::*;
+
+    const INPUT: &str = "..@@.@@@@.
+@@@.@.@.@@
+@@@@@.@.@@
+@.@@@@..@.
+@@.@@@@.@@
+.@@@@@@@.@
+.@.@.@.@@@
+@.@@@.@@@@
+.@@@@@@@@.
+@.@.@@@.@.";
+
+    crate::example_tests! {
+        Day4, recorded test_recorded_answers;
+        test_problem_one: (INPUT, One, 13),
+        test_problem_two: (INPUT, Two, 43),
+    }
+
+    fn accessible(rolls: &[Vec<bool>], x: usize, y: usize) -> bool {
+        let neighbors = (y.saturating_sub(1)..=y + 1)
+            .flat_map(|ny| (x.saturating_sub(1)..=x + 1).map(move |nx| (nx, ny)))
+            .filter(|&(nx, ny)| (nx, ny) != (x, y))
+            .filter(|&(nx, ny)| rolls.get(ny).and_then(|row| row.get(nx)) == Some(&true))
+            .count();
+
+        rolls[y][x] && neighbors < 4
+    }
+
+    fn accessible_rolls(rolls: &[Vec<bool>]) -> Vec<(usize, usize)> {
+        (0..rolls.len())
+            .flat_map(|y| (0..rolls[y].len()).map(move |x| (x, y)))
+            .filter(|&(x, y)| accessible(rolls, x, y))
+            .collect()
+    }
+
+    proptest! {
+        #[test]
+        fn solution_matches_one_roll_at_a_time(input in generators::paper_map()) {
+            let mut rolls = input
+                .lines()
+                .map(|line| line.chars().map(|c| c == '@').collect::<Vec<bool>>())
+                .collect::<Vec<Vec<bool>>>();
+            let first_round = accessible_rolls(&rolls).len();
+            let mut removed = 0;
+            while let Some(&(x, y)) = accessible_rolls(&rolls).first() {
+                rolls[y][x] = false;
+                removed += 1;
+            }
+
+            prop_assert_eq!(generators::solve(&Day4, &input)?, (first_round.into(), removed.into()));
+        }
+    }
+
+    #[test]
+    fn test_visualize_highlights_each_round() -> Result<()> {
+        let frames = Day4.frames(INPUT)?;
+        let (rounds, last) = (&frames[1..frames.len() - 1], &frames[frames.len() - 1]);
+
+        assert_eq!(frames[0].caption(), "start");
+        assert_eq!(rounds[0].caption(), "round 1: removing 13");
+        assert_eq!(
+            rounds
+                .iter()
+                .map(|frame| frame.highlights().len())
+                .sum::<usize>(),
+            43
+        );
+        assert_eq!(last.caption(), "43 removed");
+        Ok(())
+    }
+}
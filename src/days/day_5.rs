@@ -0,0 +1,175 @@
+use crate::core::*;
+use crate::interval::{Interval, IntervalSet};
+use crate::parse;
+use anyhow::{Result, anyhow};
+
+#[derive(Debug, Clone)]
+pub struct FoodDb {
+    fresh: IntervalSet,
+    foods: Vec<usize>,
+}
+
+impl TryFrom<&str> for FoodDb {
+    type Error = ParseError;
+
+    fn try_from(value: &str) -> std::result::Result<Self, Self::Error> {
+        let (ranges, foods) = parse::sections(
+            parse::lines(|line| Interval::try_from(line)),
+            parse::lines(parse::integer()),
+        )(value)?;
+
+        Ok(Self {
+            fresh: ranges.into_iter().collect(),
+            foods,
+        })
+    }
+}
+
+impl FoodDb {
+    pub fn count_fresh_foods(&self) -> usize {
+        self.foods
+            .iter()
+            .filter(|&food| self.fresh.contains(*food))
+            .count()
+    }
+
+    /// The fresh ranges merged into sorted, disjoint, non-adjacent intervals.
+    pub fn compact_ranges(&self) -> &[Interval] {
+        self.fresh.intervals()
+    }
+
+    pub fn count_possible_fresh_ingredients(&self) -> Result<usize> {
+        self.fresh
+            .size()
+            .ok_or_else(|| anyhow!("the fresh ranges cover more ids than fit in a usize"))
+    }
+}
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    const DAY: usize = 5;
+
+    type Input = FoodDb;
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        Ok(FoodDb::try_from(input)?)
+    }
+
+    fn part_one(&self, input: &Self::Input) -> Result<Answer> {
+        Ok(input.count_fresh_foods().into())
+    }
+
+    fn part_two(&self, input: &Self::Input) -> Result<Answer> {
+        Ok(input.count_possible_fresh_ingredients()?.into())
+    }
+}
+
+#[cfg(test)]
+mod day_5_tests {
+    use super::*;
+    use crate::generators;
+    use proptest::prelude::*;
+    use std::collections::BTreeSet;
+
+    const INPUT: &str = "3-5
+10-14
+16-20
+12-18
+
+1
+5
+8
+11
+17
+32";
+
+    crate::example_tests! {
+        Day5, recorded test_recorded_answers;
+        test_problem_one: (INPUT, One, 3),
+        test_problem_two: (INPUT, Two, 14),
+    }
+
+    #[test]
+    fn test_fresh_count_overflow() -> Result<()> {
+        let db = FoodDb::try_from(format!("0-{}\n\n1", usize::MAX).as_str())?;
+
+        assert!(Day5.part_two(&db).is_err());
+        Ok(())
+    }
+
+    /// Expands every range into its integers and regroups consecutive runs.
+    fn naive_compaction(ranges: &[(usize, usize)]) -> Vec<(usize, usize)> {
+        let ids = ranges
+            .iter()
+            .flat_map(|&(left, right)| left..=right)
+            .collect::<BTreeSet<usize>>();
+        let mut runs: Vec<(usize, usize)> = Vec::new();
+
+        for id in ids {
+            match runs.last_mut() {
+                Some((_, right)) if *right + 1 == id => *right = id,
+                _ => runs.push((id, id)),
+            }
+        }
+
+        runs
+    }
+
+    /// The ranges and foods written in a generated database.
+    fn contents(input: &str) -> (Vec<(usize, usize)>, Vec<usize>) {
+        let (ranges, foods) = input.split_once("\n\n").unwrap_or((input, ""));
+        let ranges = ranges
+            .lines()
+            .filter_map(|range| range.split_once('-'))
+            .map(|(left, right)| (left.parse().unwrap(), right.parse().unwrap()))
+            .collect();
+        let foods = foods.lines().map(|food| food.parse().unwrap()).collect();
+
+        (ranges, foods)
+    }
+
+    #[test]
+    fn test_ranges_compare_structurally() -> Result<()> {
+        let short = Interval::try_from("3-5")?;
+        let long = Interval::try_from("3-7")?;
+
+        assert_ne!(short, long);
+        assert!(short < long);
+        assert_eq!(BTreeSet::from([long, short, short]).len(), 2);
+        Ok(())
+    }
+
+    proptest! {
+        #[test]
+        fn compaction_matches_naive(input in generators::ingredient_db()) {
+            let (ranges, _) = contents(&input);
+            let db = FoodDb::try_from(input.as_str()).map_err(|e| TestCaseError::fail(e.to_string()))?;
+            let compacted = db
+                .compact_ranges()
+                .iter()
+                .map(|interval| (interval.start(), interval.end()))
+                .collect::<Vec<(usize, usize)>>();
+            let expected = naive_compaction(&ranges);
+
+            prop_assert_eq!(
+                db.count_possible_fresh_ingredients().ok(),
+                Some(expected.iter().map(|(left, right)| right - left + 1).sum::<usize>())
+            );
+            prop_assert_eq!(compacted, expected);
+        }
+
+        #[test]
+        fn fresh_foods_match_naive(input in generators::ingredient_db()) {
+            let (ranges, foods) = contents(&input);
+            let db = FoodDb::try_from(input.as_str()).map_err(|e| TestCaseError::fail(e.to_string()))?;
+            let expected = foods
+                .iter()
+                .filter(|&&food| ranges.iter().any(|&(left, right)| left <= food && food <= right))
+                .count();
+
+            prop_assert_eq!(db.count_fresh_foods(), expected);
+            prop_assert_eq!(generators::solve(&Day5, &input)?.0, expected.into());
+        }
+    }
+}
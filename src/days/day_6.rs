@@ -0,0 +1,254 @@
+use crate::core::*;
+use crate::parse;
+use anyhow::{Result, anyhow};
+
+#[derive(Debug)]
+enum Operator {
+    Plus,
+    Mult,
+}
+
+impl Operator {
+    fn apply(&self, numbers: &[usize]) -> Result<usize> {
+        let identity = match self {
+            Operator::Mult => 1,
+            Operator::Plus => 0,
+        };
+        let combine = |acc: usize, n: &usize| match self {
+            Operator::Mult => acc.checked_mul(*n),
+            Operator::Plus => acc.checked_add(*n),
+        };
+
+        numbers
+            .iter()
+            .try_fold(identity, combine)
+            .ok_or_else(|| anyhow!("{self:?} of {numbers:?} overflowed"))
+    }
+}
+
+impl TryFrom<&str> for Operator {
+    type Error = ParseError;
+
+    fn try_from(value: &str) -> std::result::Result<Self, Self::Error> {
+        let symbol = value.trim();
+        match symbol {
+            "*" => Ok(Operator::Mult),
+            "+" => Ok(Operator::Plus),
+            _ => Err(ParseError::new(symbol, "`*` or `+`").within(value, symbol)),
+        }
+    }
+}
+
+/// One problem on the worksheet, read both the human way (a number per row) and the
+/// cephalopod way (a number per character column, right to left).
+#[derive(Debug)]
+struct Problem {
+    operator: Operator,
+    rows: Vec<usize>,
+    columns: Vec<usize>,
+}
+
+impl Problem {
+    /// `cells` are the problem's number cells from top to bottom, all slices of `input`.
+    fn parse(input: &str, operator: &str, cells: &[&str]) -> std::result::Result<Self, ParseError> {
+        let operator =
+            Operator::try_from(operator).map_err(|error| error.within(input, operator))?;
+        let rows = cells
+            .iter()
+            .map(|cell| parse::integer()(cell).map_err(|error| error.within(input, cell)))
+            .collect::<std::result::Result<Vec<usize>, ParseError>>()?;
+
+        let width = cells
+            .iter()
+            .map(|cell| cell.chars().count())
+            .max()
+            .unwrap_or(0);
+        let columns = (0..width)
+            .rev()
+            .map(|offset| {
+                cells
+                    .iter()
+                    .filter_map(|cell| cell.chars().nth(offset))
+                    .filter(char::is_ascii_digit)
+                    .collect::<String>()
+            })
+            .filter(|digits| !digits.is_empty())
+            .map(|digits| {
+                digits.parse::<usize>().map_err(|_| {
+                    ParseError::new(digits, "a column number in range").within(input, cells[0])
+                })
+            })
+            .collect::<std::result::Result<Vec<usize>, ParseError>>()?;
+
+        Ok(Self {
+            operator,
+            rows,
+            columns,
+        })
+    }
+}
+
+pub struct Worksheet {
+    problems: Vec<Problem>,
+}
+
+impl TryFrom<&str> for Worksheet {
+    type Error = ParseError;
+
+    fn try_from(value: &str) -> std::result::Result<Self, Self::Error> {
+        let cells = parse::column_cells(value);
+        let Some((operators, numbers)) = cells.split_last() else {
+            return Ok(Worksheet { problems: vec![] });
+        };
+
+        let problems = operators
+            .iter()
+            .enumerate()
+            .map(|(idx, operator)| {
+                let column = numbers.iter().map(|row| row[idx]).collect::<Vec<&str>>();
+                Problem::parse(value, operator, &column)
+            })
+            .collect::<std::result::Result<Vec<Problem>, ParseError>>()?;
+
+        Ok(Worksheet { problems })
+    }
+}
+
+impl Worksheet {
+    fn total(&self, numbers: impl Fn(&Problem) -> &[usize]) -> Result<usize> {
+        self.problems.iter().try_fold(0usize, |total, problem| {
+            total
+                .checked_add(problem.operator.apply(numbers(problem))?)
+                .ok_or_else(|| anyhow!("worksheet total overflowed"))
+        })
+    }
+}
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    const DAY: usize = 6;
+
+    type Input = Worksheet;
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        Ok(Worksheet::try_from(input)?)
+    }
+
+    fn part_one(&self, input: &Self::Input) -> Result<Answer> {
+        Ok(input.total(|problem| &problem.rows)?.into())
+    }
+
+    fn part_two(&self, input: &Self::Input) -> Result<Answer> {
+        Ok(input.total(|problem| &problem.columns)?.into())
+    }
+}
+
+#[cfg(test)]
+mod day_6_tests {
+    use super::*;
+    use crate::generators;
+    use proptest::prelude::*;
+
+    const INPUT: &str = "123 328  51 64
+ 45 64  387 23
+  6 98  215 314
+*   +   *   +  ";
+
+    crate::example_tests! {
+        Day6, recorded test_recorded_answers;
+        test_problem_one: (INPUT, One, 4277556),
+        test_problem_two: (INPUT, Two, 3263827),
+    }
+
+    #[test]
+    fn test_parse_error_location() {
+        let error = Day6.parse("123 328\n 45 6x\n*   +  ").err().unwrap();
+
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 5: expected an integer, found \"6x\""
+        );
+    }
+
+    #[test]
+    fn test_overflow_is_an_error() -> Result<()> {
+        let worksheet = Day6.parse("99999999999 99999999999\n99999999999 1\n*           +")?;
+
+        assert!(Day6.part_one(&worksheet).is_err());
+        Ok(())
+    }
+
+    fn evaluate(operator: &str, numbers: &[usize]) -> usize {
+        match operator {
+            "*" => numbers.iter().product(),
+            _ => numbers.iter().sum(),
+        }
+    }
+
+    /// Reads the sheet a row of whitespace separated tokens at a time.
+    fn human_total(input: &str) -> usize {
+        let rows = input
+            .lines()
+            .map(|line| line.split_whitespace().collect::<Vec<&str>>())
+            .collect::<Vec<Vec<&str>>>();
+        let (operators, numbers) = rows.split_last().unwrap();
+
+        operators
+            .iter()
+            .enumerate()
+            .map(|(idx, operator)| {
+                let column = numbers
+                    .iter()
+                    .map(|row| row[idx].parse().unwrap())
+                    .collect::<Vec<usize>>();
+                evaluate(operator, &column)
+            })
+            .sum()
+    }
+
+    /// Reads the sheet a character column at a time, starting a new problem after each
+    /// column of spaces.
+    fn cephalopod_total(input: &str) -> usize {
+        let lines = input
+            .lines()
+            .map(|line| line.chars().collect::<Vec<char>>())
+            .collect::<Vec<Vec<char>>>();
+        let width = lines.iter().map(Vec::len).max().unwrap();
+        let (operators, digits) = lines.split_last().unwrap();
+        let at = |line: &Vec<char>, x: usize| line.get(x).copied().unwrap_or(' ');
+
+        let mut total = 0;
+        let mut numbers = vec![];
+        let mut operator = String::new();
+        for x in 0..=width {
+            let column = digits
+                .iter()
+                .map(|line| at(line, x))
+                .filter(|c| *c != ' ')
+                .collect::<String>();
+            if at(operators, x) != ' ' {
+                operator = at(operators, x).to_string();
+            }
+
+            if column.is_empty() {
+                total += evaluate(&operator, &numbers);
+                numbers.clear();
+            } else {
+                numbers.push(column.parse().unwrap());
+            }
+        }
+
+        total
+    }
+
+    proptest! {
+        #[test]
+        fn solution_matches_naive(input in generators::worksheet()) {
+            prop_assert_eq!(
+                generators::solve(&Day6, &input)?,
+                (human_total(&input).into(), cephalopod_total(&input).into())
+            );
+        }
+    }
+}
@@ -0,0 +1,288 @@
+use crate::core::*;
+use crate::grid::{self, Position};
+use crate::parse;
+use crate::viz::{Frame, Recorder};
+use anyhow::{Result, anyhow};
+use std::collections::{HashMap, HashSet};
+use std::fmt::{Display, Formatter};
+
+#[derive(Debug, Clone)]
+pub enum Tile {
+    Manifold,
+    Splitter,
+    Beam,
+    Empty,
+}
+
+impl Display for Tile {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let symbol = match self {
+            Tile::Manifold => 'S',
+            Tile::Splitter => '^',
+            Tile::Beam => '|',
+            Tile::Empty => '.',
+        };
+
+        write!(f, "{symbol}")
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Grid {
+    active_splitters: HashSet<Position>,
+    tiles: grid::Grid<Tile>,
+    manifold: Position,
+    active_beams: HashSet<Position>,
+}
+
+impl TryFrom<&str> for Grid {
+    type Error = ParseError;
+
+    fn try_from(value: &str) -> std::result::Result<Self, Self::Error> {
+        let tiles = parse::grid(parse::one_of([
+            ('S', Tile::Manifold),
+            ('^', Tile::Splitter),
+            ('.', Tile::Empty),
+        ]))(value)?;
+        let manifold = tiles
+            .find(|tile| matches!(tile, Tile::Manifold))
+            .ok_or_else(|| ParseError::new(value.lines().next().unwrap_or(""), "a manifold `S`"))?;
+
+        Ok(Self {
+            active_splitters: HashSet::new(),
+            tiles,
+            manifold,
+            active_beams: HashSet::new(),
+        })
+    }
+}
+
+impl Display for Grid {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.tiles)
+    }
+}
+
+impl Grid {
+    pub fn activate(&mut self, recorder: &mut Recorder) {
+        self.propagate_beam(self.manifold);
+
+        for step in 1.. {
+            if self.active_beams.is_empty() {
+                break;
+            }
+            recorder.record(|| {
+                Frame::new(
+                    &self.tiles,
+                    format!("step {step}: {} beams", self.active_beams.len()),
+                )
+                .highlight(self.active_beams.iter().copied())
+            });
+
+            let next_active_beams = self
+                .active_beams
+                .clone()
+                .into_iter()
+                .collect::<Vec<Position>>();
+            next_active_beams
+                .iter()
+                .for_each(|beam| self.propagate_beam(*beam));
+        }
+    }
+
+    pub fn propagate_beam(&mut self, coord: Position) {
+        if let Some(next_beam_pos) = self.tiles.offset(coord, (0, 1)) {
+            match self.tiles[next_beam_pos] {
+                Tile::Empty => {
+                    self.set_tile(next_beam_pos, Tile::Beam);
+                }
+                Tile::Splitter => {
+                    self.active_splitters.insert(next_beam_pos);
+                    self.maybe_set_tile_at(next_beam_pos, (-1, 0), Tile::Beam);
+                    self.maybe_set_tile_at(next_beam_pos, (1, 0), Tile::Beam);
+                }
+                _ => {}
+            };
+        }
+
+        self.active_beams.remove(&coord);
+    }
+
+    pub fn maybe_set_tile_at(&mut self, coord: Position, direction: (isize, isize), tile: Tile) {
+        if let Some(next) = self.tiles.offset(coord, direction) {
+            self.set_tile(next, tile);
+        }
+    }
+
+    pub fn set_tile(&mut self, coord: Position, tile: Tile) {
+        if let Tile::Beam = tile {
+            self.active_beams.insert(coord);
+        }
+        self.tiles[coord] = tile;
+    }
+
+    pub fn many_worlds(&self) -> Result<usize> {
+        let mut visited: HashMap<Position, usize> = HashMap::new();
+
+        self.many_world_rec(self.manifold, &mut visited)
+    }
+
+    pub fn many_world_rec(
+        &self,
+        current: Position,
+        visited: &mut HashMap<Position, usize>,
+    ) -> Result<usize> {
+        if let Some(cached_child_paths) = visited.get(&current) {
+            return Ok(*cached_child_paths);
+        }
+
+        let next_positions = match self.tiles[current] {
+            Tile::Splitter => {
+                let left = self.tiles.offset(current, (-1, 0));
+                let right = self.tiles.offset(current, (1, 0));
+
+                vec![left, right]
+            }
+            _ => {
+                vec![self.tiles.offset(current, (0, 1))]
+            }
+        };
+
+        let mut path_count: usize = 0;
+
+        for maybe_next in next_positions {
+            let paths = match maybe_next {
+                Some(next_coord) => self.many_world_rec(next_coord, visited)?,
+                None => 1,
+            };
+            path_count = path_count
+                .checked_add(paths)
+                .ok_or_else(|| anyhow!("too many worlds to count in a usize"))?;
+        }
+
+        visited.insert(current, path_count);
+
+        Ok(path_count)
+    }
+}
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    const DAY: usize = 7;
+
+    type Input = Grid;
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        Ok(Grid::try_from(input)?)
+    }
+
+    fn part_one(&self, input: &Self::Input) -> Result<Answer> {
+        let mut grid = input.clone();
+        grid.activate(&mut Recorder::off());
+        Ok(grid.active_splitters.len().into())
+    }
+
+    fn part_two(&self, input: &Self::Input) -> Result<Answer> {
+        let mut grid = input.clone();
+        grid.activate(&mut Recorder::off());
+        Ok(grid.many_worlds()?.into())
+    }
+
+    fn visualize(&self, input: &Self::Input, recorder: &mut Recorder) -> Result<()> {
+        let mut grid = input.clone();
+        grid.activate(recorder);
+        recorder.record(|| {
+            let caption = format!("{} splitters hit", grid.active_splitters.len());
+            Frame::new(&grid.tiles, caption).highlight(grid.active_splitters.iter().copied())
+        });
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod day_7_tests {
+    use super::*;
+    use crate::generators;
+    use proptest::prelude::*;
+    use std::collections::BTreeMap;
+
+    const INPUT: &str = ".......S.......
+...............
+.......^.......
+...............
+......^.^......
+...............
+.....^.^.^.....
+...............
+....^.^...^....
+...............
+...^.^...^.^...
+...............
+..^...^.....^..
+...............
+.^.^.^.^.^...^.
+...............";
+
+    crate::example_tests! {
+        Day7, recorded test_recorded_answers;
+        test_problem_one: (INPUT, One, 21),
+        test_problem_two: (INPUT, Two, 40),
+    }
+
+    #[test]
+    fn test_missing_manifold() {
+        let error = Grid::try_from("...\n.^.").unwrap_err();
+
+        assert_eq!(error.expected, "a manifold `S`");
+        assert_eq!((error.line, error.column), (1, 1));
+    }
+
+    /// Drops the beam a row at a time, returning the splitters hit and the timelines that
+    /// reach the bottom.
+    fn row_by_row(input: &str) -> (usize, usize) {
+        let rows = input
+            .lines()
+            .map(|line| line.chars().collect::<Vec<char>>())
+            .collect::<Vec<Vec<char>>>();
+        let start = rows[0].iter().position(|c| *c == 'S').unwrap();
+        let mut timelines = BTreeMap::from([(start, 1usize)]);
+        let mut splits = 0;
+
+        for row in &rows[1..] {
+            let mut next = BTreeMap::new();
+            for (x, count) in timelines {
+                if row[x] == '^' {
+                    splits += 1;
+                    *next.entry(x - 1).or_default() += count;
+                    *next.entry(x + 1).or_default() += count;
+                } else {
+                    *next.entry(x).or_default() += count;
+                }
+            }
+            timelines = next;
+        }
+
+        (splits, timelines.values().sum())
+    }
+
+    proptest! {
+        #[test]
+        fn solution_matches_row_by_row(input in generators::splitter_grid()) {
+            let (splits, timelines) = row_by_row(&input);
+
+            prop_assert_eq!(generators::solve(&Day7, &input)?, (splits.into(), timelines.into()));
+        }
+    }
+
+    #[test]
+    fn test_visualize_ends_on_the_splitters_hit() -> Result<()> {
+        let frames = Day7.frames(INPUT)?;
+        let last = frames.last().ok_or_else(|| anyhow!("no frames recorded"))?;
+
+        assert_eq!(frames[0].caption(), "step 1: 1 beams");
+        assert_eq!(last.caption(), "21 splitters hit");
+        assert_eq!(last.highlights().len(), 21);
+        Ok(())
+    }
+}
@@ -0,0 +1,201 @@
+//! Random puzzle inputs for property tests.
+//!
+//! Every generator yields the raw text a day's parser reads, kept inside the puzzle's stated
+//! domain (for instance, batteries are rated 1 to 9 and rotations move at least one click) so a
+//! property test can compare the real solver with a naive oracle on the same input.
+
+use crate::core::{Answer, Problem, Solution, Solver};
+use proptest::prelude::*;
+
+/// Runs both parts, turning any error into a failed test case.
+pub fn solve<S: Solution>(solution: &S, input: &str) -> Result<(Answer, Answer), TestCaseError> {
+    let answers = solution
+        .run(input, &[Problem::One, Problem::Two])
+        .map_err(|error| TestCaseError::fail(format!("{error:#}")))?;
+
+    match <[Answer; 2]>::try_from(answers) {
+        Ok([one, two]) => Ok((one, two)),
+        Err(answers) => Err(TestCaseError::fail(format!(
+            "expected two answers, got {answers:?}"
+        ))),
+    }
+}
+
+/// Day 1: one `L<n>` or `R<n>` rotation per line, some of them several turns of the dial.
+pub fn rotations() -> impl Strategy<Value = String> {
+    let rotation = (prop::bool::ANY, prop_oneof![1usize..100, 1usize..1000]);
+
+    prop::collection::vec(rotation, 1..40).prop_map(|rotations| {
+        rotations
+            .into_iter()
+            .map(|(left, count)| format!("{}{count}", if left { 'L' } else { 'R' }))
+            .collect::<Vec<String>>()
+            .join("\n")
+    })
+}
+
+/// Day 2: comma separated `a-b` id ranges, most of them straddling a repeated-digit id and
+/// sometimes overlapping an earlier range.
+pub fn id_ranges() -> impl Strategy<Value = String> {
+    let repeated = (1usize..1000, 2usize..5).prop_map(|(pattern, times)| {
+        pattern
+            .to_string()
+            .repeat(times)
+            .parse::<usize>()
+            .unwrap_or(pattern)
+    });
+    let start = prop_oneof![
+        1usize..100_000,
+        (repeated, 0usize..50).prop_map(|(id, before)| id.saturating_sub(before).max(1)),
+    ];
+
+    let range = (start, 0usize..100).prop_map(|(start, width)| (start, start + width));
+
+    (
+        prop::collection::vec(range, 1..8),
+        prop::option::of(0usize..50),
+    )
+        .prop_map(|(mut ranges, overlap)| {
+            if let Some(shift) = overlap {
+                let (start, end) = ranges[0];
+                ranges.push((start + shift.min(end - start), end + shift));
+            }
+
+            ranges
+                .into_iter()
+                .map(|(start, end)| format!("{start}-{end}"))
+                .collect::<Vec<String>>()
+                .join(",")
+        })
+}
+
+/// Day 3: banks of 12 to 16 batteries, one bank per line.
+pub fn banks() -> impl Strategy<Value = String> {
+    let bank = prop::collection::vec(1u32..=9, 12..=16)
+        .prop_map(|digits| digits.iter().map(u32::to_string).collect::<String>());
+
+    prop::collection::vec(bank, 1..5).prop_map(|banks| banks.join("\n"))
+}
+
+/// Day 4: a rectangle of paper rolls (`@`) and empty floor (`.`).
+pub fn paper_map() -> impl Strategy<Value = String> {
+    (1usize..12, 1usize..12)
+        .prop_flat_map(|(width, height)| {
+            prop::collection::vec(prop::collection::vec(prop::bool::ANY, width), height)
+        })
+        .prop_map(|rows| {
+            rows.iter()
+                .map(|row| {
+                    row.iter()
+                        .map(|&paper| if paper { '@' } else { '.' })
+                        .collect::<String>()
+                })
+                .collect::<Vec<String>>()
+                .join("\n")
+        })
+}
+
+/// Day 5: possibly overlapping fresh ranges, a blank line, then ingredient ids to check.
+pub fn ingredient_db() -> impl Strategy<Value = String> {
+    let ranges = prop::collection::vec((0usize..200, 0usize..20), 1..12);
+    let foods = prop::collection::vec(0usize..250, 1..20);
+
+    (ranges, foods).prop_map(|(ranges, foods)| {
+        let ranges = ranges
+            .iter()
+            .map(|(start, width)| format!("{start}-{}", start + width))
+            .collect::<Vec<String>>()
+            .join("\n");
+        let foods = foods
+            .iter()
+            .map(usize::to_string)
+            .collect::<Vec<String>>()
+            .join("\n");
+
+        format!("{ranges}\n\n{foods}")
+    })
+}
+
+/// Day 6: problems side by side, each a column of numbers aligned left or right over its
+/// operator, with a blank column between problems and trailing spaces sometimes trimmed.
+pub fn worksheet() -> impl Strategy<Value = String> {
+    (1usize..5, 1usize..6, prop::bool::ANY)
+        .prop_flat_map(|(height, count, trim)| {
+            let problem = (
+                prop::collection::vec(1usize..10_000, height),
+                prop::bool::ANY,
+                prop::bool::ANY,
+            );
+            (prop::collection::vec(problem, count), Just(trim))
+        })
+        .prop_map(|(problems, trim)| {
+            let height = problems[0].0.len();
+            let mut lines = vec![String::new(); height + 1];
+
+            for (idx, (numbers, multiply, left_aligned)) in problems.iter().enumerate() {
+                let width = numbers
+                    .iter()
+                    .map(|n| n.to_string().len())
+                    .max()
+                    .unwrap_or(1);
+                let separator = if idx == 0 { "" } else { " " };
+
+                for (line, n) in lines.iter_mut().zip(numbers) {
+                    let cell = if *left_aligned {
+                        format!("{n:<width$}")
+                    } else {
+                        format!("{n:>width$}")
+                    };
+                    line.push_str(separator);
+                    line.push_str(&cell);
+                }
+
+                let operator = if *multiply { '*' } else { '+' };
+                lines[height].push_str(separator);
+                lines[height].push_str(&format!("{operator:<width$}"));
+            }
+
+            lines
+                .iter()
+                .map(|line| if trim { line.trim_end() } else { line })
+                .collect::<Vec<&str>>()
+                .join("\n")
+        })
+}
+
+/// Day 7: a manifold `S` on the top row above alternating blank and splitter rows. Splitters
+/// stay off the edges and never touch each other, as in the puzzle.
+pub fn splitter_grid() -> impl Strategy<Value = String> {
+    (1usize..8, 1usize..8)
+        .prop_flat_map(|(half_width, splitter_rows)| {
+            let width = 2 * half_width + 1;
+            let row = prop::collection::vec(prop::bool::ANY, width);
+            (
+                0..width,
+                prop::collection::vec(row, splitter_rows),
+                Just(width),
+            )
+        })
+        .prop_map(|(manifold, splitter_rows, width)| {
+            let mut lines = vec![
+                (0..width)
+                    .map(|x| if x == manifold { 'S' } else { '.' })
+                    .collect::<String>(),
+            ];
+
+            for row in splitter_rows {
+                let mut tiles = vec!['.'; width];
+                for x in 1..width - 1 {
+                    if row[x] && tiles[x - 1] != '^' {
+                        tiles[x] = '^';
+                    }
+                }
+
+                lines.push(".".repeat(width));
+                lines.push(tiles.into_iter().collect());
+            }
+            lines.push(".".repeat(width));
+
+            lines.join("\n")
+        })
+}
@@ -0,0 +1,305 @@
+use crate::core::ParseError;
+use anyhow::{Result, anyhow};
+use std::fmt::{Display, Formatter};
+use std::ops::{Index, IndexMut};
+
+/// `(x, y)` with the origin in the top-left corner, matching the order of the input text.
+pub type Position = (usize, usize);
+
+pub const ORTHOGONAL: [(isize, isize); 4] = [(0, -1), (1, 0), (0, 1), (-1, 0)];
+
+pub const ADJACENT: [(isize, isize); 8] = [
+    (-1, -1),
+    (0, -1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+    (0, 1),
+    (-1, 1),
+    (-1, 0),
+];
+
+/// A dense, row-major grid of tiles.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    width: usize,
+    height: usize,
+}
+
+impl<T> Grid<T> {
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Result<Self> {
+        let width = rows.first().map_or(0, Vec::len);
+        let height = rows.len();
+
+        if let Some((y, row)) = rows.iter().enumerate().find(|(_, row)| row.len() != width) {
+            return Err(anyhow!(
+                "row {y} is {} wide but the first row is {width} wide",
+                row.len()
+            ));
+        }
+
+        Ok(Self {
+            cells: rows.into_iter().flatten().collect(),
+            width,
+            height,
+        })
+    }
+
+    /// Maps every character of a rectangular block of text to a tile.
+    pub fn parse(input: &str, mut tile: impl FnMut(char) -> T) -> Result<Self, ParseError> {
+        Self::try_parse(input, |c| Ok(tile(c)))
+    }
+
+    /// Like [`Grid::parse`], but `tile` may reject a character; the error is placed at it.
+    pub fn try_parse(
+        input: &str,
+        mut tile: impl FnMut(char) -> Result<T, ParseError>,
+    ) -> Result<Self, ParseError> {
+        let width = input.lines().next().map_or(0, |line| line.chars().count());
+        let mut cells = Vec::new();
+        let mut height = 0;
+
+        for (y, line) in input.lines().enumerate() {
+            let length = line.chars().count();
+            if length > width {
+                let extra = line.chars().skip(width).collect::<String>();
+                return Err(
+                    ParseError::new(extra, format!("rows {width} tiles wide")).at(y + 1, width + 1)
+                );
+            }
+            if length < width {
+                return Err(
+                    ParseError::new("", format!("rows {width} tiles wide")).at(y + 1, length + 1)
+                );
+            }
+
+            for (x, c) in line.chars().enumerate() {
+                cells.push(tile(c).map_err(|error| error.at(y + 1, x + 1))?);
+            }
+            height += 1;
+        }
+
+        Ok(Self {
+            cells,
+            width,
+            height,
+        })
+    }
+
+    /// Like [`Grid::parse`], but short lines are padded with `pad` up to the widest line.
+    pub fn parse_padded(input: &str, pad: char, mut tile: impl FnMut(char) -> T) -> Self {
+        let width = input
+            .lines()
+            .map(|line| line.chars().count())
+            .max()
+            .unwrap_or(0);
+        let height = input.lines().count();
+
+        let cells = input
+            .lines()
+            .flat_map(|line| {
+                let padding = width - line.chars().count();
+                line.chars().chain(std::iter::repeat_n(pad, padding))
+            })
+            .map(&mut tile)
+            .collect();
+
+        Self {
+            cells,
+            width,
+            height,
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, (x, y): Position) -> bool {
+        x < self.width && y < self.height
+    }
+
+    fn index_of(&self, position: Position) -> Option<usize> {
+        self.contains(position)
+            .then(|| position.1 * self.width + position.0)
+    }
+
+    pub fn get(&self, position: Position) -> Option<&T> {
+        self.index_of(position).map(|idx| &self.cells[idx])
+    }
+
+    pub fn get_mut(&mut self, position: Position) -> Option<&mut T> {
+        self.index_of(position).map(|idx| &mut self.cells[idx])
+    }
+
+    /// Returns the previous tile, or `None` when `position` is out of bounds.
+    pub fn set(&mut self, position: Position, tile: T) -> Option<T> {
+        self.get_mut(position)
+            .map(|cell| std::mem::replace(cell, tile))
+    }
+
+    /// The position one step of `(dx, dy)` away, if it is still inside the grid.
+    pub fn offset(&self, (x, y): Position, (dx, dy): (isize, isize)) -> Option<Position> {
+        let next = (x.checked_add_signed(dx)?, y.checked_add_signed(dy)?);
+
+        self.contains(next).then_some(next)
+    }
+
+    pub fn neighbors<'a>(
+        &'a self,
+        position: Position,
+        directions: &'a [(isize, isize)],
+    ) -> impl Iterator<Item = Position> + 'a {
+        directions
+            .iter()
+            .filter_map(move |direction| self.offset(position, *direction))
+    }
+
+    /// Up, right, down and left neighbors that are inside the grid.
+    pub fn neighbors4(&self, position: Position) -> impl Iterator<Item = Position> + '_ {
+        self.neighbors(position, &ORTHOGONAL)
+    }
+
+    /// All eight surrounding neighbors that are inside the grid.
+    pub fn neighbors8(&self, position: Position) -> impl Iterator<Item = Position> + '_ {
+        self.neighbors(position, &ADJACENT)
+    }
+
+    pub fn positions(&self) -> impl Iterator<Item = Position> + use<T> {
+        let width = self.width;
+        (0..self.cells.len()).map(move |idx| (idx % width, idx / width))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Position, &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width.max(1))
+    }
+
+    /// The tiles in column `x` from top to bottom; empty when `x` is past the right edge.
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        let cells = if self.contains((x, 0)) {
+            &self.cells[x..]
+        } else {
+            &[]
+        };
+
+        cells.iter().step_by(self.width.max(1))
+    }
+
+    pub fn find(&self, predicate: impl Fn(&T) -> bool) -> Option<Position> {
+        self.iter()
+            .find(|(_, tile)| predicate(tile))
+            .map(|(position, _)| position)
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            cells: self.cells.iter().map(f).collect(),
+            width: self.width,
+            height: self.height,
+        }
+    }
+}
+
+impl<T> Index<Position> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, position: Position) -> &Self::Output {
+        self.get(position).unwrap_or_else(|| {
+            panic!(
+                "{position:?} is outside a {}x{} grid",
+                self.width, self.height
+            )
+        })
+    }
+}
+
+impl<T> IndexMut<Position> for Grid<T> {
+    fn index_mut(&mut self, position: Position) -> &mut Self::Output {
+        let (width, height) = (self.width, self.height);
+        self.get_mut(position)
+            .unwrap_or_else(|| panic!("{position:?} is outside a {width}x{height} grid"))
+    }
+}
+
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for (y, row) in self.rows().enumerate() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+            for tile in row {
+                write!(f, "{tile}")?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod grid_tests {
+    use super::*;
+
+    const INPUT: &str = "#..
+.#.
+..#";
+
+    #[test]
+    fn test_parse_and_display() -> Result<()> {
+        let grid = Grid::parse(INPUT, |c| c)?;
+
+        assert_eq!((grid.width(), grid.height()), (3, 3));
+        assert_eq!(grid[(1, 1)], '#');
+        assert_eq!(grid.get((3, 0)), None);
+        assert_eq!(grid.to_string(), INPUT);
+        Ok(())
+    }
+
+    #[test]
+    fn test_ragged_input() {
+        let error = Grid::parse("..\n.", |c| c).unwrap_err();
+        assert_eq!((error.line, error.column), (2, 2));
+
+        let padded = Grid::parse_padded("..\n.", ' ', |c| c);
+        assert_eq!(padded.to_string(), "..\n. ");
+    }
+
+    #[test]
+    fn test_neighbors_are_bounds_checked() -> Result<()> {
+        let grid = Grid::parse(INPUT, |c| c == '#')?;
+
+        assert_eq!(
+            grid.neighbors4((0, 0)).collect::<Vec<_>>(),
+            vec![(1, 0), (0, 1)]
+        );
+        assert_eq!(grid.neighbors8((0, 0)).count(), 3);
+        assert_eq!(grid.neighbors8((1, 1)).count(), 8);
+        assert_eq!(
+            grid.neighbors8((2, 2))
+                .filter(|p| grid[*p])
+                .collect::<Vec<_>>(),
+            vec![(1, 1)]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_columns_and_find() -> Result<()> {
+        let grid = Grid::parse("abc\ndef", |c| c)?;
+
+        assert_eq!(grid.column(1).collect::<String>(), "be");
+        assert_eq!(grid.column(3).count(), 0);
+        assert_eq!(grid.find(|c| *c == 'f'), Some((2, 1)));
+        assert_eq!(grid.positions().last(), Some((2, 1)));
+        Ok(())
+    }
+}
@@ -0,0 +1,311 @@
+use crate::core::ParseError;
+use crate::parse;
+use anyhow::{Result, anyhow};
+use std::fmt::{Display, Formatter};
+use std::ops::RangeInclusive;
+
+/// An inclusive range of integers, written `start-end` in puzzle inputs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Interval {
+    start: usize,
+    end: usize,
+}
+
+impl Interval {
+    pub fn new(start: usize, end: usize) -> Result<Self> {
+        if start > end {
+            return Err(anyhow!("interval start {start} is after its end {end}"));
+        }
+
+        Ok(Self { start, end })
+    }
+
+    pub fn single(value: usize) -> Self {
+        Self {
+            start: value,
+            end: value,
+        }
+    }
+
+    pub fn start(&self) -> usize {
+        self.start
+    }
+
+    pub fn end(&self) -> usize {
+        self.end
+    }
+
+    /// Number of integers in the interval, never zero; `None` only for `0-usize::MAX`, whose
+    /// size does not fit in a `usize`.
+    pub fn size(&self) -> Option<usize> {
+        (self.end - self.start).checked_add(1)
+    }
+
+    pub fn contains(&self, value: usize) -> bool {
+        self.start <= value && value <= self.end
+    }
+
+    pub fn overlaps(&self, other: &Interval) -> bool {
+        self.start <= other.end && other.start <= self.end
+    }
+
+    /// Overlapping or directly adjacent, i.e. the union is a single interval.
+    pub fn touches(&self, other: &Interval) -> bool {
+        self.start <= other.end.saturating_add(1) && other.start <= self.end.saturating_add(1)
+    }
+
+    pub fn intersection(&self, other: &Interval) -> Option<Interval> {
+        self.overlaps(other).then(|| Interval {
+            start: self.start.max(other.start),
+            end: self.end.min(other.end),
+        })
+    }
+
+    pub fn iter(&self) -> RangeInclusive<usize> {
+        self.start..=self.end
+    }
+}
+
+impl TryFrom<&str> for Interval {
+    type Error = ParseError;
+
+    fn try_from(value: &str) -> std::result::Result<Self, Self::Error> {
+        let (start, end) = parse::pair("-", parse::integer(), parse::integer())(value)?;
+
+        Interval::new(start, end).map_err(|_| {
+            let trimmed = value.trim();
+            ParseError::new(trimmed, "a range whose start is not after its end")
+                .within(value, trimmed)
+        })
+    }
+}
+
+impl Display for Interval {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}-{}", self.start, self.end)
+    }
+}
+
+/// Sorted, disjoint and non-adjacent intervals; inserting merges anything it touches.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct IntervalSet {
+    intervals: Vec<Interval>,
+}
+
+impl IntervalSet {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn insert(&mut self, interval: Interval) {
+        let first = self
+            .intervals
+            .partition_point(|existing| existing.end.saturating_add(1) < interval.start);
+        let last = self
+            .intervals
+            .partition_point(|existing| existing.start <= interval.end.saturating_add(1));
+
+        let merged = self.intervals[first..last]
+            .iter()
+            .fold(interval, |merged, existing| Interval {
+                start: merged.start.min(existing.start),
+                end: merged.end.max(existing.end),
+            });
+
+        self.intervals.splice(first..last, [merged]);
+    }
+
+    pub fn contains(&self, value: usize) -> bool {
+        let idx = self
+            .intervals
+            .partition_point(|interval| interval.end < value);
+
+        self.intervals
+            .get(idx)
+            .is_some_and(|interval| interval.start <= value)
+    }
+
+    /// Total number of integers covered, or `None` when that does not fit in a `usize`.
+    pub fn size(&self) -> Option<usize> {
+        self.intervals.iter().try_fold(0usize, |total, interval| {
+            total.checked_add(interval.size()?)
+        })
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.intervals.is_empty()
+    }
+
+    pub fn intervals(&self) -> &[Interval] {
+        &self.intervals
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &Interval> {
+        self.intervals.iter()
+    }
+
+    pub fn union(&self, other: &IntervalSet) -> IntervalSet {
+        let mut union = self.clone();
+        union.extend(other.iter().copied());
+
+        union
+    }
+
+    pub fn intersection(&self, other: &IntervalSet) -> IntervalSet {
+        let mut intervals = Vec::new();
+        let (mut a, mut b) = (
+            self.intervals.iter().peekable(),
+            other.intervals.iter().peekable(),
+        );
+
+        while let (Some(left), Some(right)) = (a.peek(), b.peek()) {
+            if let Some(overlap) = left.intersection(right) {
+                intervals.push(overlap);
+            }
+
+            if left.end < right.end {
+                a.next();
+            } else {
+                b.next();
+            }
+        }
+
+        IntervalSet { intervals }
+    }
+
+    pub fn difference(&self, other: &IntervalSet) -> IntervalSet {
+        match (self.intervals.first(), self.intervals.last()) {
+            (Some(first), Some(last)) => self.intersection(&other.complement(Interval {
+                start: first.start,
+                end: last.end,
+            })),
+            _ => IntervalSet::new(),
+        }
+    }
+
+    /// Everything inside `bounds` that the set does not cover.
+    pub fn complement(&self, bounds: Interval) -> IntervalSet {
+        let mut intervals = Vec::new();
+        let mut next = Some(bounds.start);
+
+        for interval in self.iter() {
+            let Some(start) = next else { break };
+            if interval.end < start {
+                continue;
+            }
+            if interval.start > bounds.end {
+                break;
+            }
+            if interval.start > start {
+                intervals.push(Interval {
+                    start,
+                    end: interval.start - 1,
+                });
+            }
+            next = interval.end.checked_add(1);
+        }
+
+        if let Some(start) = next
+            && start <= bounds.end
+        {
+            intervals.push(Interval {
+                start,
+                end: bounds.end,
+            });
+        }
+
+        IntervalSet { intervals }
+    }
+}
+
+impl Extend<Interval> for IntervalSet {
+    fn extend<I: IntoIterator<Item = Interval>>(&mut self, iter: I) {
+        for interval in iter {
+            self.insert(interval);
+        }
+    }
+}
+
+impl FromIterator<Interval> for IntervalSet {
+    fn from_iter<I: IntoIterator<Item = Interval>>(iter: I) -> Self {
+        let mut set = IntervalSet::new();
+        set.extend(iter);
+
+        set
+    }
+}
+
+#[cfg(test)]
+mod interval_tests {
+    use super::*;
+
+    fn set(raw: &str) -> Result<IntervalSet> {
+        Ok(raw
+            .split(",")
+            .map(Interval::try_from)
+            .collect::<std::result::Result<IntervalSet, ParseError>>()?)
+    }
+
+    #[test]
+    fn test_insert_merges_overlapping_and_adjacent() -> Result<()> {
+        let merged = set("10-14,3-5,16-20,12-18,6-6")?;
+
+        assert_eq!(merged, set("3-6,10-20")?);
+        assert_eq!(merged.size(), Some(15));
+        assert!(merged.contains(6));
+        assert!(!merged.contains(7));
+        assert!(merged.contains(20));
+        assert!(!merged.contains(21));
+        Ok(())
+    }
+
+    #[test]
+    fn test_set_operations() -> Result<()> {
+        let a = set("1-10,20-30")?;
+        let b = set("5-25")?;
+
+        assert_eq!(a.union(&b), set("1-30")?);
+        assert_eq!(a.intersection(&b), set("5-10,20-25")?);
+        assert_eq!(a.difference(&b), set("1-4,26-30")?);
+        assert_eq!(b.difference(&a), set("11-19")?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_complement_within_bounds() -> Result<()> {
+        let a = set("3-5,8-9")?;
+
+        assert_eq!(a.complement(Interval::new(0, 10)?), set("0-2,6-7,10-10")?);
+        assert_eq!(a.complement(Interval::new(4, 8)?), set("6-7")?);
+        assert!(set("0-5")?.complement(Interval::new(1, 4)?).is_empty());
+        assert_eq!(
+            set(&format!("0-{}", usize::MAX - 1))?.complement(Interval::new(0, usize::MAX)?),
+            IntervalSet::from_iter([Interval::single(usize::MAX)])
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_size_overflow() -> Result<()> {
+        let everything = Interval::new(0, usize::MAX)?;
+
+        assert_eq!(Interval::single(usize::MAX).size(), Some(1));
+        assert_eq!(Interval::new(1, usize::MAX)?.size(), Some(usize::MAX));
+        assert_eq!(everything.size(), None);
+        assert_eq!(
+            set(&format!("0-{}", usize::MAX - 1))?.size(),
+            Some(usize::MAX)
+        );
+        assert_eq!(set(&format!("6-{},0-5", usize::MAX))?.size(), None);
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_rejects_reversed_bounds() {
+        assert!(Interval::try_from("5-3").is_err());
+        assert!(Interval::try_from("5").is_err());
+
+        let error = Interval::try_from(" 12-x4").unwrap_err();
+        assert_eq!((error.column, error.text.as_str()), (5, "x4"));
+    }
+}
@@ -1 +1,15 @@
+pub mod answers;
+pub mod bench;
+pub mod client;
 pub mod core;
+pub mod days;
+#[cfg(test)]
+pub mod generators;
+pub mod grid;
+pub mod interval;
+pub mod parse;
+pub mod scaffold;
+pub mod submit;
+pub mod testing;
+pub mod viz;
+pub mod watch;
@@ -1,5 +1,413 @@
-mod core;
+use anyhow::{Result, anyhow};
+use aoc_2025::answers::AnswerStore;
+use aoc_2025::bench;
+use aoc_2025::client::{self, AocClient, Fetched};
+use aoc_2025::core::{
+    Answer, InputArgs, OutputFormat, Problem, Registry, TimedRun, day_name, format_answer_table,
+    get_data, input_dir, input_path,
+};
+use aoc_2025::submit::{self, History, Outcome};
+use aoc_2025::viz;
+use aoc_2025::watch::Watch;
+use aoc_2025::{days, scaffold};
+use clap::{Parser, Subcommand};
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
 
-fn main() {
-    println!("Hello, world!");
+#[derive(Parser, Debug)]
+#[command(version, about, long_about = None)]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand, Debug)]
+enum Command {
+    /// Run a single day, or every registered day with --all
+    Run(RunArgs),
+    /// Re-run every recorded answer and report any that changed
+    Verify(VerifyArgs),
+    /// Repeatedly time a day's parse and parts and report min/median/max
+    Bench(BenchArgs),
+    /// Generate the solver, binary and empty input for a new day
+    New { day: usize },
+    /// Download a day's puzzle input unless it is already cached
+    Fetch {
+        day: usize,
+
+        #[arg(long, default_value = client::DEFAULT_BASE_URL)]
+        base_url: String,
+    },
+    /// Solve a part and submit the answer, refusing known-wrong answers and cooldowns
+    Submit {
+        day: usize,
+
+        #[arg(value_enum)]
+        part: Problem,
+
+        #[arg(long, default_value = client::DEFAULT_BASE_URL)]
+        base_url: String,
+    },
+    /// Animate a grid day's solve in the terminal or save it as an asciicast
+    Viz(VizArgs),
+    /// Rebuild and re-run a day whenever its directory or the library changes
+    Watch {
+        day: usize,
+
+        /// Milliseconds between polls for changed files
+        #[arg(long, default_value_t = 500)]
+        interval: u64,
+    },
+}
+
+#[derive(clap::Args, Debug)]
+struct RunArgs {
+    #[arg(required_unless_present = "all", conflicts_with = "all")]
+    day: Option<usize>,
+
+    #[arg(long, conflicts_with_all = ["input", "stdin", "inputs"])]
+    all: bool,
+
+    /// Only run this part; both parts are run when omitted
+    #[arg(short, long, value_enum)]
+    part: Option<Problem>,
+
+    /// Save the answers to the day's answers.toml
+    #[arg(long, conflicts_with_all = ["input", "stdin", "inputs"])]
+    record: bool,
+
+    /// Report parse and per-part wall time
+    #[arg(long)]
+    time: bool,
+
+    #[arg(long, value_enum, default_value_t = OutputFormat::Text)]
+    format: OutputFormat,
+
+    #[command(flatten)]
+    input: InputArgs,
+}
+
+#[derive(clap::Args, Debug)]
+struct BenchArgs {
+    #[arg(required_unless_present = "all", conflicts_with = "all")]
+    day: Option<usize>,
+
+    #[arg(long, conflicts_with_all = ["input", "stdin", "inputs", "all_inputs"])]
+    all: bool,
+
+    /// Only bench this part; both parts are benched when omitted
+    #[arg(short, long, value_enum)]
+    part: Option<Problem>,
+
+    /// Untimed runs before measuring
+    #[arg(long, default_value_t = 3)]
+    warmup: usize,
+
+    /// Timed runs to collect
+    #[arg(long, default_value_t = 10)]
+    iterations: usize,
+
+    #[command(flatten)]
+    input: InputArgs,
+}
+
+#[derive(clap::Args, Debug)]
+struct VizArgs {
+    day: usize,
+
+    /// Frames shown per second
+    #[arg(long, default_value_t = 10.0)]
+    fps: f64,
+
+    /// Write an asciicast v2 recording here instead of playing in the terminal
+    #[arg(long, value_name = "FILE")]
+    cast: Option<PathBuf>,
+
+    /// Play without ANSI colors
+    #[arg(long)]
+    no_color: bool,
+
+    #[command(flatten)]
+    input: InputArgs,
+}
+
+#[derive(clap::Args, Debug)]
+struct VerifyArgs {
+    /// Only verify this day; every registered day is verified when omitted
+    day: Option<usize>,
+}
+
+fn selected_days(registry: &Registry, day: Option<usize>) -> Vec<usize> {
+    match day {
+        Some(day) => vec![day],
+        None => registry.days().collect(),
+    }
+}
+
+fn selected_parts(part: Option<Problem>) -> Vec<Problem> {
+    match part {
+        Some(part) => vec![part],
+        None => vec![Problem::One, Problem::Two],
+    }
+}
+
+fn run(args: RunArgs) -> Result<()> {
+    let registry = days::registry();
+    let parts = selected_parts(args.part);
+    let mut failures = 0;
+
+    // A failing day is reported and counted so that `--all` still runs the rest.
+    for day in selected_days(&registry, args.day) {
+        match run_day(&args, &registry, &parts, day) {
+            Ok(failed) => failures += failed,
+            Err(error) => {
+                failures += 1;
+                eprintln!("day {day}: FAILED {error:#}");
+            }
+        }
+    }
+
+    if failures > 0 {
+        return Err(anyhow!("{failures} input(s) failed to solve"));
+    }
+
+    Ok(())
+}
+
+/// Runs every selected input for one day and returns how many of them failed.
+fn run_day(args: &RunArgs, registry: &Registry, parts: &[Problem], day: usize) -> Result<usize> {
+    let solver = registry.get(day)?;
+    let inputs = args.input.read_all(&day_name(day))?;
+
+    let runs = inputs
+        .into_iter()
+        .map(|input| (input.name, solver.run_timed(&input.data, parts)))
+        .collect::<Vec<(String, Result<TimedRun>)>>();
+
+    let rows = runs
+        .iter()
+        .map(|(name, run)| {
+            let answers = run
+                .as_ref()
+                .map(TimedRun::answers)
+                .map_err(|error| anyhow!("{error:#}"));
+            (name.clone(), answers)
+        })
+        .collect::<Vec<(String, Result<Vec<Answer>>)>>();
+    let failures = rows.iter().filter(|(_, answers)| answers.is_err()).count();
+
+    if args.format == OutputFormat::Json {
+        for (name, run) in &runs {
+            match run {
+                Ok(run) => {
+                    for record in run.records(day, name) {
+                        println!("{}", serde_json::to_string(&record)?);
+                    }
+                }
+                Err(error) => eprintln!("day {day} / {name}: {error:#}"),
+            }
+        }
+    } else if let [(name, answers)] = rows.as_slice() {
+        match answers {
+            Ok(answers) => {
+                for (part, answer) in parts.iter().zip(answers) {
+                    println!("day {day} / part {part}: {answer}");
+                }
+            }
+            Err(error) => eprintln!("day {day} / {name}: FAILED {error:#}"),
+        }
+    } else {
+        println!("day {day}\n{}", format_answer_table(parts, &rows));
+    }
+
+    if args.time && args.format == OutputFormat::Text {
+        for (name, run) in &runs {
+            if let Ok(run) = run {
+                println!("day {day} / {name} / {run}");
+            }
+        }
+    }
+
+    if args.record {
+        let mut store = AnswerStore::load(&day_name(day))?;
+        for (name, answers) in &rows {
+            let Ok(answers) = answers else { continue };
+            for (part, answer) in parts.iter().zip(answers) {
+                store.record(name, *part, answer.clone());
+            }
+        }
+        store.save(&day_name(day))?;
+    }
+
+    Ok(failures)
+}
+
+fn bench(args: BenchArgs) -> Result<()> {
+    let registry = days::registry();
+    let parts = selected_parts(args.part);
+
+    for day in selected_days(&registry, args.day) {
+        let solver = registry.get(day)?;
+
+        for input in args.input.read_all(&day_name(day))? {
+            let report = bench::bench(solver, &input, &parts, args.warmup, args.iterations)?;
+            println!("{report}");
+        }
+    }
+
+    Ok(())
+}
+
+fn verify(args: VerifyArgs) -> Result<()> {
+    let registry = days::registry();
+    let mut failures = 0;
+
+    for day in selected_days(&registry, args.day) {
+        let solver = registry.get(day)?;
+        let store = AnswerStore::load(&day_name(day))?;
+
+        if store.inputs().next().is_none() {
+            println!("day {day}: no recorded answers");
+            continue;
+        }
+
+        for (name, recorded) in store.inputs() {
+            let parts = [Problem::One, Problem::Two]
+                .into_iter()
+                .filter(|part| recorded.get(*part).is_some())
+                .collect::<Vec<Problem>>();
+
+            let path = input_dir().join(day_name(day)).join(name);
+            let answers = fs::read_to_string(&path)
+                .map_err(|error| anyhow!("failed to read {}: {error}", path.display()))
+                .and_then(|input| solver.run(&input, &parts));
+
+            match answers {
+                Ok(answers) => {
+                    for (part, actual) in parts.iter().zip(answers) {
+                        let verdict = store.verify(name, *part, actual);
+                        if verdict.is_mismatch() {
+                            failures += 1;
+                        }
+                        println!("day {day} / {name} / part {part}: {verdict}");
+                    }
+                }
+                Err(error) => {
+                    failures += 1;
+                    println!("day {day} / {name}: FAILED {error:#}");
+                }
+            }
+        }
+    }
+
+    if failures > 0 {
+        return Err(anyhow!("{failures} recorded answer(s) failed verification"));
+    }
+
+    Ok(())
+}
+
+fn visualize(args: VizArgs) -> Result<()> {
+    let registry = days::registry();
+    let solver = registry.get(args.day)?;
+
+    let mut frames = vec![];
+    for input in args.input.read_all(&day_name(args.day))? {
+        frames.extend(solver.frames(&input.data)?);
+    }
+
+    match &args.cast {
+        Some(path) => {
+            fs::write(path, viz::asciicast(&frames, args.fps)?)?;
+            println!("wrote {} frames to {}", frames.len(), path.display());
+        }
+        None => viz::play(&frames, args.fps, !args.no_color, &mut io::stdout())?,
+    }
+
+    Ok(())
+}
+
+fn new(day: usize) -> Result<()> {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+
+    for path in scaffold::new_day(root, day)? {
+        println!("wrote {}", path.display());
+    }
+
+    Ok(())
+}
+
+fn fetch(day: usize, base_url: &str) -> Result<()> {
+    let path = input_path(&day_name(day));
+
+    // Check the cache before asking for a session so cached days work without one.
+    if client::is_cached(&path) {
+        println!("day {day} input already cached at {}", path.display());
+        return Ok(());
+    }
+
+    let client = AocClient::new(base_url, &client::session_token()?);
+    if let Fetched::Downloaded(path) = client::fetch_input_cached(&client, day, &path)? {
+        println!("downloaded day {day} input to {}", path.display());
+    }
+
+    Ok(())
+}
+
+fn submit(day: usize, part: Problem, base_url: &str) -> Result<()> {
+    let registry = days::registry();
+    let input = get_data(&day_name(day))?;
+    let answer = registry
+        .get(day)?
+        .run(&input, &[part])?
+        .pop()
+        .ok_or_else(|| anyhow!("day {day} produced no answer for part {part}"))?;
+
+    let mut history = History::load(&day_name(day))?;
+    history.check(part, &answer, submit::now())?;
+
+    let client = AocClient::new(base_url, &client::session_token()?);
+    let response = submit::submit(&client, &mut history, day, part, &answer, submit::now());
+    history.save(&day_name(day))?;
+    let response = response?;
+
+    println!("day {day} / part {part}: {answer} is {}", response.outcome);
+    if let Some(cooldown) = response.cooldown {
+        println!("next submission allowed in {}s", cooldown.as_secs());
+    }
+
+    if response.outcome == Outcome::Correct {
+        let mut store = AnswerStore::load(&day_name(day))?;
+        store.record("input.txt", part, answer);
+        store.save(&day_name(day))?;
+    }
+
+    Ok(())
+}
+
+fn watch(day: usize, interval: u64) -> Result<()> {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+
+    Watch::new(root, day, Duration::from_millis(interval)).run()
+}
+
+fn main() -> Result<()> {
+    let cli = Cli::parse();
+
+    match cli.command {
+        Command::Run(args) => run(args),
+        Command::Verify(args) => verify(args),
+        Command::Bench(args) => bench(args),
+        Command::New { day } => new(day),
+        Command::Fetch { day, base_url } => fetch(day, &base_url),
+        Command::Submit {
+            day,
+            part,
+            base_url,
+        } => submit(day, part, &base_url),
+        Command::Viz(args) => visualize(args),
+        Command::Watch { day, interval } => watch(day, interval),
+    }
 }
@@ -0,0 +1,295 @@
+//! Composable parsers for puzzle inputs.
+//!
+//! A parser takes a fragment of the input and returns either a value or a [`ParseError`]
+//! relative to that fragment. Combinators split fragments into smaller pieces and re-anchor
+//! any error from a piece, so by the time it reaches the runner it points at the line and
+//! column of the whole input.
+
+use crate::core::ParseError;
+use crate::grid::Grid;
+use std::str::FromStr;
+
+pub type Parsed<T> = Result<T, ParseError>;
+
+pub trait Parser<T>: Fn(&str) -> Parsed<T> {}
+
+impl<T, F: Fn(&str) -> Parsed<T>> Parser<T> for F {}
+
+/// An unsigned decimal integer, ignoring surrounding whitespace.
+pub fn integer<T: FromStr>() -> impl Parser<T> {
+    |input: &str| {
+        let text = input.trim();
+        if text.is_empty() || !text.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(ParseError::new(text, "an integer").within(input, text));
+        }
+
+        text.parse()
+            .map_err(|_| ParseError::new(text, "an integer in range").within(input, text))
+    }
+}
+
+/// An integer with an optional leading `+` or `-`, ignoring surrounding whitespace.
+pub fn signed<T: FromStr>() -> impl Parser<T> {
+    |input: &str| {
+        let text = input.trim();
+        let magnitude = text.strip_prefix(['+', '-']).unwrap_or(text);
+        if magnitude.is_empty() || !magnitude.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(ParseError::new(text, "a signed integer").within(input, text));
+        }
+
+        text.parse()
+            .map_err(|_| ParseError::new(text, "an integer in range").within(input, text))
+    }
+}
+
+/// Every character as a single decimal digit.
+pub fn digits() -> impl Parser<Vec<u32>> {
+    |input: &str| {
+        input
+            .chars()
+            .enumerate()
+            .map(|(idx, c)| {
+                c.to_digit(10)
+                    .ok_or_else(|| ParseError::new(c, "a digit").at(1, idx + 1))
+            })
+            .collect()
+    }
+}
+
+pub fn map<A, B>(parser: impl Parser<A>, f: impl Fn(A) -> B) -> impl Parser<B> {
+    move |input: &str| parser(input).map(&f)
+}
+
+/// One of a fixed set of prefixes, such as the `L` and `R` of a rotation, then `rest`.
+pub fn keyed<K: Copy, T, const N: usize>(
+    keys: [(&'static str, K); N],
+    rest: impl Parser<T>,
+) -> impl Parser<(K, T)> {
+    move |input: &str| {
+        let (prefix, key) = keys
+            .iter()
+            .find(|(prefix, _)| input.starts_with(prefix))
+            .ok_or_else(|| {
+                let expected = keys
+                    .iter()
+                    .map(|(prefix, _)| format!("`{prefix}`"))
+                    .collect::<Vec<String>>()
+                    .join(" or ");
+                ParseError::new(input, expected)
+            })?;
+        let remainder = &input[prefix.len()..];
+
+        Ok((
+            *key,
+            rest(remainder).map_err(|error| error.within(input, remainder))?,
+        ))
+    }
+}
+
+/// Two values either side of the first `separator`.
+pub fn pair<A, B>(
+    separator: &'static str,
+    left: impl Parser<A>,
+    right: impl Parser<B>,
+) -> impl Parser<(A, B)> {
+    move |input: &str| {
+        let (a, b) = input.split_once(separator).ok_or_else(|| {
+            ParseError::new(input, format!("two values separated by `{separator}`"))
+        })?;
+
+        Ok((
+            left(a).map_err(|error| error.within(input, a))?,
+            right(b).map_err(|error| error.within(input, b))?,
+        ))
+    }
+}
+
+/// Items between each `separator`; surrounding whitespace is ignored and blank input is empty.
+pub fn separated<T>(separator: &'static str, item: impl Parser<T>) -> impl Parser<Vec<T>> {
+    move |input: &str| {
+        let trimmed = input.trim();
+        if trimmed.is_empty() {
+            return Ok(vec![]);
+        }
+
+        trimmed
+            .split(separator)
+            .map(|piece| item(piece).map_err(|error| error.within(input, piece)))
+            .collect()
+    }
+}
+
+/// One item per line.
+pub fn lines<T>(item: impl Parser<T>) -> impl Parser<Vec<T>> {
+    move |input: &str| {
+        input
+            .lines()
+            .map(|line| item(line).map_err(|error| error.within(input, line)))
+            .collect()
+    }
+}
+
+/// Two blocks separated by a blank line.
+pub fn sections<A, B>(first: impl Parser<A>, second: impl Parser<B>) -> impl Parser<(A, B)> {
+    move |input: &str| {
+        let (a, b) = input.split_once("\n\n").ok_or_else(|| {
+            ParseError::new("", "a blank line between sections")
+                .within(input, &input[input.len()..])
+        })?;
+
+        Ok((
+            first(a).map_err(|error| error.within(input, a))?,
+            second(b).map_err(|error| error.within(input, b))?,
+        ))
+    }
+}
+
+/// Maps each listed character to its tile and rejects any other.
+pub fn one_of<T: Clone, const N: usize>(tiles: [(char, T); N]) -> impl Fn(char) -> Parsed<T> {
+    move |c| {
+        tiles
+            .iter()
+            .find(|(symbol, _)| *symbol == c)
+            .map(|(_, tile)| tile.clone())
+            .ok_or_else(|| {
+                let expected = tiles
+                    .iter()
+                    .map(|(symbol, _)| format!("`{symbol}`"))
+                    .collect::<Vec<String>>()
+                    .join(" or ");
+                ParseError::new(c, expected)
+            })
+    }
+}
+
+/// A rectangular block of characters, one tile each.
+pub fn grid<T>(tile: impl Fn(char) -> Parsed<T>) -> impl Parser<Grid<T>> {
+    move |input: &str| Grid::try_parse(input, &tile)
+}
+
+/// Splits a block of lines into fixed-width columns wherever every line has a space (or has
+/// already ended). Returns one row per line, each with a cell per column; cells are slices of
+/// `input` and are shorter than the column where a line ends early.
+pub fn column_cells(input: &str) -> Vec<Vec<&str>> {
+    let blank = Grid::parse_padded(input, ' ', |c| c == ' ');
+    let is_blank = |x: usize| blank.column(x).all(|&space| space);
+
+    // Past the right edge a column has no cells, so it reads as blank and closes the last span.
+    let mut spans = vec![];
+    let mut start = None;
+    for x in 0..=blank.width() {
+        match (start, is_blank(x)) {
+            (None, false) => start = Some(x),
+            (Some(from), true) => {
+                spans.push((from, x));
+                start = None;
+            }
+            _ => {}
+        }
+    }
+
+    input
+        .lines()
+        .map(|line| {
+            let offsets = line
+                .char_indices()
+                .map(|(offset, _)| offset)
+                .chain(std::iter::once(line.len()))
+                .collect::<Vec<usize>>();
+            let byte = |x: usize| offsets.get(x).copied().unwrap_or(line.len());
+
+            spans
+                .iter()
+                .map(|&(from, to)| &line[byte(from)..byte(to)])
+                .collect()
+        })
+        .collect()
+}
+
+/// Like [`column_cells`], parsing every cell with `cell`.
+pub fn columns<T>(cell: impl Parser<T>) -> impl Parser<Vec<Vec<T>>> {
+    move |input: &str| {
+        column_cells(input)
+            .into_iter()
+            .map(|row| {
+                row.into_iter()
+                    .map(|text| cell(text).map_err(|error| error.within(input, text)))
+                    .collect()
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod parse_tests {
+    use super::*;
+    use anyhow::Result;
+
+    #[test]
+    fn test_numbers() -> Result<()> {
+        assert_eq!(integer::<usize>()(" 42 ")?, 42);
+        assert_eq!(signed::<i64>()("-17")?, -17);
+        assert_eq!(digits()("9071")?, vec![9, 0, 7, 1]);
+
+        let error = integer::<usize>()("-3").unwrap_err();
+        assert_eq!((error.column, error.expected.as_str()), (1, "an integer"));
+        assert_eq!(digits()("12x").unwrap_err().column, 3);
+        Ok(())
+    }
+
+    #[test]
+    fn test_errors_point_into_the_whole_input() {
+        let ranges = separated(",", pair("-", integer::<u32>(), integer::<u32>()));
+        let error = ranges("1-2,3-4,5-x6\n").unwrap_err();
+        assert_eq!(
+            (error.line, error.column, error.text.as_str()),
+            (1, 11, "x6")
+        );
+
+        let rotations = lines(keyed([("L", -1), ("R", 1)], integer::<i32>()));
+        let error = rotations("L5\nR10\nX3").unwrap_err();
+        assert_eq!((error.line, error.column), (3, 1));
+        assert_eq!(error.expected, "`L` or `R`");
+
+        let db = sections(lines(integer::<u8>()), lines(integer::<u8>()));
+        assert_eq!(db("1\n2\n\n3").ok(), Some((vec![1, 2], vec![3])));
+        let error = db("1\n\n2\n300").unwrap_err();
+        assert_eq!((error.line, error.column), (4, 1));
+        assert_eq!(db("1\n2").unwrap_err().line, 2);
+    }
+
+    #[test]
+    fn test_columns() -> Result<()> {
+        let input = "123 328\n 45 64\n  6\n*   +";
+        let cells = column_cells(input);
+
+        assert_eq!(
+            cells,
+            vec![
+                vec!["123", "328"],
+                vec![" 45", "64"],
+                vec!["  6", ""],
+                vec!["*  ", "+"],
+            ]
+        );
+        assert_eq!(
+            columns(integer::<u32>())("1  2\n30 4")?,
+            vec![vec![1, 2], vec![30, 4]]
+        );
+
+        let error = columns(integer::<u32>())("1  2\n30 x").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 4));
+        Ok(())
+    }
+
+    #[test]
+    fn test_grid() -> Result<()> {
+        let tiles = grid(one_of([('#', true), ('.', false)]));
+
+        assert!(tiles("#.\n.#")?[(1, 1)]);
+        let error = tiles("#.\n.?").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 2));
+        assert_eq!(error.expected, "`#` or `.`");
+        Ok(())
+    }
+}
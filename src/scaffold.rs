@@ -0,0 +1,135 @@
+use anyhow::{Context, Result, anyhow};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+const DAY_TEMPLATE: &str = include_str!("../templates/day.rs.tmpl");
+const MAIN_TEMPLATE: &str = include_str!("../templates/main.rs.tmpl");
+const DAY_PLACEHOLDER: &str = "__DAY__";
+
+fn render(template: &str, day: usize) -> String {
+    template.replace(DAY_PLACEHOLDER, &day.to_string())
+}
+
+/// Adds `pub mod day_N;` and `.register(day_N::DayN)` after the last existing entries in `days.rs`.
+fn register_day(days_rs: &str, day: usize) -> Result<String> {
+    let mut lines = days_rs.lines().map(String::from).collect::<Vec<String>>();
+
+    let last_mod = lines
+        .iter()
+        .rposition(|line| line.starts_with("pub mod day_"))
+        .ok_or_else(|| anyhow!("could not find any `pub mod day_N;` declarations"))?;
+    lines.insert(last_mod + 1, format!("pub mod day_{day};"));
+
+    let last_register = lines
+        .iter()
+        .rposition(|line| line.trim_start().starts_with(".register("))
+        .ok_or_else(|| anyhow!("could not find any `.register(..)` calls"))?;
+    let indent = lines[last_register]
+        .chars()
+        .take_while(|c| c.is_whitespace())
+        .collect::<String>();
+    let terminated = lines[last_register].ends_with(';');
+    if terminated {
+        lines[last_register].pop();
+    }
+    lines.insert(
+        last_register + 1,
+        format!(
+            "{indent}.register(day_{day}::Day{day}){}",
+            if terminated { ";" } else { "" }
+        ),
+    );
+
+    Ok(lines.join("\n") + "\n")
+}
+
+/// Generates the solver module, binary and empty input for a new day under `root`, refusing to
+/// touch a day that already has any of them. Returns the files that were created.
+pub fn new_day(root: &Path, day: usize) -> Result<Vec<PathBuf>> {
+    let days_rs_path = root.join("src/days.rs");
+    let module_path = root.join(format!("src/days/day_{day}.rs"));
+    let bin_dir = root.join(format!("src/bin/day-{day}"));
+    let main_path = bin_dir.join("main.rs");
+    let input_path = bin_dir.join("input.txt");
+
+    let days_rs = fs::read_to_string(&days_rs_path)
+        .with_context(|| format!("failed to read {}", days_rs_path.display()))?;
+
+    if days_rs.contains(&format!("pub mod day_{day};")) {
+        return Err(anyhow!(
+            "day {day} is already registered in {}",
+            days_rs_path.display()
+        ));
+    }
+
+    if let Some(existing) = [&module_path, &main_path, &input_path]
+        .into_iter()
+        .find(|path| path.exists())
+    {
+        return Err(anyhow!(
+            "day {day} already exists at {}",
+            existing.display()
+        ));
+    }
+
+    let registered = register_day(&days_rs, day)?;
+
+    fs::create_dir_all(&bin_dir)?;
+    fs::write(&module_path, render(DAY_TEMPLATE, day))?;
+    fs::write(&main_path, render(MAIN_TEMPLATE, day))?;
+    fs::write(&input_path, "")?;
+    fs::write(&days_rs_path, registered)?;
+
+    Ok(vec![module_path, main_path, input_path, days_rs_path])
+}
+
+#[cfg(test)]
+mod scaffold_tests {
+    use super::*;
+    use std::env;
+
+    const DAYS_RS: &str = "use crate::core::Registry;
+
+pub mod day_1;
+
+pub fn registry() -> Registry {
+    let mut registry = Registry::new();
+
+    registry
+        .register(day_1::Day1);
+
+    registry
+}
+";
+
+    #[test]
+    fn test_register_day() -> Result<()> {
+        let registered = register_day(DAYS_RS, 2)?;
+
+        assert!(registered.contains("pub mod day_1;\npub mod day_2;\n"));
+        assert!(
+            registered
+                .contains("        .register(day_1::Day1)\n        .register(day_2::Day2);\n")
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_new_day_refuses_existing() -> Result<()> {
+        let root = env::temp_dir().join(format!("aoc-scaffold-{}", std::process::id()));
+        fs::create_dir_all(root.join("src/days"))?;
+        fs::write(root.join("src/days.rs"), DAYS_RS)?;
+
+        let created = new_day(&root, 2)?;
+        let module = fs::read_to_string(root.join("src/days/day_2.rs"))?;
+        let second_attempt = new_day(&root, 2);
+        let existing_day = new_day(&root, 1);
+        fs::remove_dir_all(&root)?;
+
+        assert_eq!(created.len(), 4);
+        assert!(module.contains("impl Solution for Day2"));
+        assert!(second_attempt.is_err());
+        assert!(existing_day.is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,350 @@
+use crate::client::AocClient;
+use crate::core::{Answer, Problem, input_dir};
+use anyhow::{Context, Result, anyhow};
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
+use std::fs;
+use std::path::PathBuf;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// Lives next to a day's `input.txt` and records every submission made for it.
+pub const HISTORY_FILE: &str = "submissions.toml";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Outcome {
+    Correct,
+    TooHigh,
+    TooLow,
+    Incorrect,
+    TooRecent,
+    WrongLevel,
+    Unknown,
+}
+
+impl Outcome {
+    pub fn is_wrong(&self) -> bool {
+        matches!(
+            self,
+            Outcome::TooHigh | Outcome::TooLow | Outcome::Incorrect
+        )
+    }
+}
+
+impl Display for Outcome {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let message = match self {
+            Outcome::Correct => "correct",
+            Outcome::TooHigh => "incorrect, too high",
+            Outcome::TooLow => "incorrect, too low",
+            Outcome::Incorrect => "incorrect",
+            Outcome::TooRecent => "submitted too recently",
+            Outcome::WrongLevel => "not the current level (already solved?)",
+            Outcome::Unknown => "unrecognised response",
+        };
+
+        write!(f, "{message}")
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Response {
+    pub outcome: Outcome,
+    pub cooldown: Option<Duration>,
+}
+
+fn parse_cooldown(html: &str) -> Option<Duration> {
+    let remaining = Regex::new(r"You have (?:(\d+)m )?(\d+)s left to wait").ok()?;
+    if let Some(captures) = remaining.captures(html) {
+        let minutes = captures
+            .get(1)
+            .map_or(Ok(0), |m| m.as_str().parse::<u64>())
+            .ok()?;
+        let seconds = captures.get(2)?.as_str().parse::<u64>().ok()?;
+        return Some(Duration::from_secs(minutes * 60 + seconds));
+    }
+
+    let penalty = Regex::new(r"(?i)wait (one|\d+) minutes? before trying again").ok()?;
+    let minutes = match penalty.captures(html)?.get(1)?.as_str() {
+        "one" => 1,
+        count => count.parse::<u64>().ok()?,
+    };
+
+    Some(Duration::from_secs(minutes * 60))
+}
+
+pub fn parse_response(html: &str) -> Response {
+    let outcome = if html.contains("That's the right answer") {
+        Outcome::Correct
+    } else if html.contains("That's not the right answer") {
+        if html.contains("your answer is too high") {
+            Outcome::TooHigh
+        } else if html.contains("your answer is too low") {
+            Outcome::TooLow
+        } else {
+            Outcome::Incorrect
+        }
+    } else if html.contains("You gave an answer too recently") {
+        Outcome::TooRecent
+    } else if html.contains("You don't seem to be solving the right level") {
+        Outcome::WrongLevel
+    } else {
+        Outcome::Unknown
+    };
+
+    Response {
+        outcome,
+        cooldown: parse_cooldown(html),
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Attempt {
+    pub part: u8,
+    pub answer: String,
+    pub outcome: Outcome,
+    pub submitted_at: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub retry_after: Option<u64>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct History {
+    #[serde(default, rename = "attempt")]
+    attempts: Vec<Attempt>,
+}
+
+pub fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|elapsed| elapsed.as_secs())
+        .unwrap_or(0)
+}
+
+impl History {
+    pub fn path(day: &str) -> PathBuf {
+        input_dir().join(day).join(HISTORY_FILE)
+    }
+
+    pub fn load(day: &str) -> Result<Self> {
+        let path = Self::path(day);
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+
+        let raw = fs::read_to_string(&path)
+            .with_context(|| format!("failed to read submission history at {}", path.display()))?;
+
+        toml::from_str(&raw).with_context(|| format!("malformed history file {}", path.display()))
+    }
+
+    pub fn save(&self, day: &str) -> Result<()> {
+        let path = Self::path(day);
+
+        fs::write(&path, toml::to_string(self)?)
+            .with_context(|| format!("failed to write submission history to {}", path.display()))
+    }
+
+    pub fn attempts(&self) -> &[Attempt] {
+        &self.attempts
+    }
+
+    /// Refuses anything the site is known to reject: a cooldown still running, a part that is
+    /// already solved, an answer already marked wrong, or one outside a known too high/low bound.
+    pub fn check(&self, problem: Problem, answer: &Answer, now: u64) -> Result<()> {
+        if let Some(retry_after) = self.attempts.iter().filter_map(|a| a.retry_after).max()
+            && retry_after > now
+        {
+            return Err(anyhow!(
+                "still cooling down, try again in {}s",
+                retry_after - now
+            ));
+        }
+
+        let part = problem.number();
+        let rendered = answer.to_string();
+
+        for attempt in self.attempts.iter().filter(|attempt| attempt.part == part) {
+            if attempt.outcome == Outcome::Correct {
+                return Err(anyhow!(
+                    "part {part} was already solved with {}",
+                    attempt.answer
+                ));
+            }
+
+            if attempt.outcome.is_wrong() && attempt.answer == rendered {
+                return Err(anyhow!(
+                    "{rendered} was already submitted for part {part} and was {}",
+                    attempt.outcome
+                ));
+            }
+
+            if let (Answer::Number(candidate), Ok(previous)) =
+                (answer, attempt.answer.parse::<usize>())
+            {
+                let out_of_bounds = match attempt.outcome {
+                    Outcome::TooHigh => *candidate >= previous,
+                    Outcome::TooLow => *candidate <= previous,
+                    _ => false,
+                };
+
+                if out_of_bounds {
+                    return Err(anyhow!(
+                        "{candidate} cannot be right for part {part}: {previous} was {}",
+                        attempt.outcome
+                    ));
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    pub fn record(&mut self, problem: Problem, answer: &Answer, response: &Response, now: u64) {
+        self.attempts.push(Attempt {
+            part: problem.number(),
+            answer: answer.to_string(),
+            outcome: response.outcome,
+            submitted_at: now,
+            retry_after: response.cooldown.map(|cooldown| now + cooldown.as_secs()),
+        });
+    }
+}
+
+/// Checks the history, posts the answer and records the attempt whatever the outcome.
+pub fn submit(
+    client: &AocClient,
+    history: &mut History,
+    day: usize,
+    problem: Problem,
+    answer: &Answer,
+    now: u64,
+) -> Result<Response> {
+    history.check(problem, answer, now)?;
+
+    let html = client.submit_answer(day, problem.number(), &answer.to_string())?;
+    let response = parse_response(&html);
+    history.record(problem, answer, &response, now);
+
+    Ok(response)
+}
+
+#[cfg(test)]
+mod submit_tests {
+    use super::*;
+    use crate::client::stub_server;
+
+    const TOO_HIGH: &str = "<article><p>That's not the right answer; your answer is too high. \
+        Please wait one minute before trying again.</p></article>";
+    const TOO_RECENT: &str = "<article><p>You gave an answer too recently; you have to wait \
+        after submitting an answer before trying again.  You have 1m 5s left to wait.</p></article>";
+    const CORRECT: &str =
+        "<article><p>That's the right answer!  You are one gold star closer.</p></article>";
+
+    #[test]
+    fn test_parse_response() {
+        assert_eq!(
+            parse_response(TOO_HIGH),
+            Response {
+                outcome: Outcome::TooHigh,
+                cooldown: Some(Duration::from_secs(60))
+            }
+        );
+        assert_eq!(
+            parse_response(TOO_RECENT),
+            Response {
+                outcome: Outcome::TooRecent,
+                cooldown: Some(Duration::from_secs(65))
+            }
+        );
+        assert_eq!(parse_response(CORRECT).outcome, Outcome::Correct);
+        assert_eq!(parse_response("<html></html>").outcome, Outcome::Unknown);
+    }
+
+    #[test]
+    fn test_submit_records_and_throttles() -> Result<()> {
+        let (base_url, requests) = stub_server::serve(vec![(200, TOO_HIGH), (200, CORRECT)]);
+        let client = AocClient::new(&base_url, "abc123");
+        let mut history = History::default();
+
+        let first = submit(
+            &client,
+            &mut history,
+            1,
+            Problem::One,
+            &Answer::from(500),
+            1_000,
+        )?;
+        let request = requests.recv()?;
+        assert_eq!(first.outcome, Outcome::TooHigh);
+        assert!(request.starts_with("POST /2025/day/1/answer HTTP/1.1"));
+        assert!(request.ends_with("level=1&answer=500"));
+
+        let cooling = submit(
+            &client,
+            &mut history,
+            1,
+            Problem::One,
+            &Answer::from(400),
+            1_030,
+        );
+        assert!(cooling.is_err());
+
+        let repeated = submit(
+            &client,
+            &mut history,
+            1,
+            Problem::One,
+            &Answer::from(500),
+            2_000,
+        );
+        let above_bound = submit(
+            &client,
+            &mut history,
+            1,
+            Problem::One,
+            &Answer::from(501),
+            2_000,
+        );
+        assert!(repeated.is_err());
+        assert!(above_bound.is_err());
+
+        let second = submit(
+            &client,
+            &mut history,
+            1,
+            Problem::One,
+            &Answer::from(400),
+            2_000,
+        )?;
+        assert_eq!(second.outcome, Outcome::Correct);
+        assert_eq!(history.attempts().len(), 2);
+
+        let solved = submit(
+            &client,
+            &mut history,
+            1,
+            Problem::One,
+            &Answer::from(400),
+            3_000,
+        );
+        assert!(solved.is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_history_round_trip() -> Result<()> {
+        let mut history = History::default();
+        history.record(
+            Problem::Two,
+            &Answer::from(42),
+            &parse_response(TOO_HIGH),
+            1_000,
+        );
+
+        let raw = toml::to_string(&history)?;
+        assert_eq!(toml::from_str::<History>(&raw)?, history);
+        Ok(())
+    }
+}
@@ -0,0 +1,87 @@
+//! Support for [`example_tests!`](crate::example_tests), which generates a day's sample and
+//! recorded-answer tests.
+
+use crate::answers::AnswerStore;
+use crate::core::{Answer, Problem, Solution, Solver, day_name, input_dir};
+use anyhow::{Context, Result};
+use std::fs;
+
+/// Parses `input` and checks a single part against the puzzle's sample answer.
+pub fn check_example<S: Solution>(
+    solution: &S,
+    input: &str,
+    problem: Problem,
+    expected: Answer,
+) -> Result<()> {
+    let parsed = solution.parse(input)?;
+    let actual = solution.part(&parsed, problem)?;
+
+    assert_eq!(actual, expected, "day {} part {problem} example", S::DAY);
+    Ok(())
+}
+
+/// Re-solves every input in the day's `answers.toml`; a day with nothing recorded passes.
+pub fn check_recorded<S: Solution>(solution: &S) -> Result<()> {
+    let day = day_name(S::DAY);
+    let store = AnswerStore::load(&day)?;
+
+    for (name, recorded) in store.inputs() {
+        let path = input_dir().join(&day).join(name);
+        let input = fs::read_to_string(&path)
+            .with_context(|| format!("failed to read recorded input {}", path.display()))?;
+
+        for problem in [Problem::One, Problem::Two] {
+            let Some(expected) = recorded.get(problem) else {
+                continue;
+            };
+            let actual = solution.run(&input, &[problem])?;
+
+            assert_eq!(
+                actual.first(),
+                Some(expected),
+                "{day} / {name} / part {problem}"
+            );
+        }
+    }
+
+    Ok(())
+}
+
+/// Generates a `#[test]` per `(input, part, expected)` example, plus a test re-checking the
+/// recorded answers in `answers.toml` when `, recorded <name>` follows the solution.
+///
+/// ```ignore
+/// aoc_2025::example_tests! {
+///     Day1::default(), recorded test_recorded_answers;
+///     test_problem_one: (INPUT, One, 3),
+///     test_problem_two: (INPUT, Two, 6),
+/// }
+/// ```
+#[macro_export]
+macro_rules! example_tests {
+    (
+        $solution:expr $(, $(#[$recorded_meta:meta])* recorded $recorded:ident)?;
+        $($(#[$meta:meta])* $name:ident: ($input:expr, $part:ident, $expected:expr)),* $(,)?
+    ) => {
+        $(
+            #[test]
+            $(#[$recorded_meta])*
+            fn $recorded() -> anyhow::Result<()> {
+                $crate::testing::check_recorded(&$solution)
+            }
+        )?
+
+        $(
+            #[test]
+            $(#[$meta])*
+            fn $name() -> anyhow::Result<()> {
+                $crate::testing::check_example(
+                    &$solution,
+                    $input,
+                    $crate::core::Problem::$part,
+                    $crate::core::Answer::from($expected),
+                )
+            }
+        )*
+    };
+}
@@ -0,0 +1,192 @@
+//! Frame-by-frame pictures of a grid day's solve.
+//!
+//! A solver takes a [`Recorder`] and hands it a [`Frame`] at each interesting step; an
+//! [`Recorder::off`] recorder never builds them, so the normal solve pays nothing. The
+//! recorded frames can be played back in the terminal or saved as an asciicast file.
+
+use crate::grid::{Grid, Position};
+use anyhow::Result;
+use serde_json::json;
+use std::collections::BTreeSet;
+use std::fmt::Display;
+use std::io::Write;
+use std::thread;
+use std::time::Duration;
+
+const HIGHLIGHT: &str = "\x1b[1;33m";
+const RESET: &str = "\x1b[0m";
+const CLEAR: &str = "\x1b[2J\x1b[H";
+
+/// A snapshot of a grid with some cells picked out and a line describing the step.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Frame {
+    cells: Grid<String>,
+    highlights: BTreeSet<Position>,
+    caption: String,
+}
+
+impl Frame {
+    pub fn new<T: Display>(grid: &Grid<T>, caption: impl Into<String>) -> Self {
+        Self {
+            cells: grid.map(T::to_string),
+            highlights: BTreeSet::new(),
+            caption: caption.into(),
+        }
+    }
+
+    pub fn highlight(mut self, positions: impl IntoIterator<Item = Position>) -> Self {
+        self.highlights.extend(positions);
+        self
+    }
+
+    pub fn caption(&self) -> &str {
+        &self.caption
+    }
+
+    pub fn highlights(&self) -> &BTreeSet<Position> {
+        &self.highlights
+    }
+
+    /// The grid then the caption, with highlighted cells in bold yellow when `color` is set.
+    pub fn render(&self, color: bool) -> String {
+        let mut out = String::new();
+
+        for (y, row) in self.cells.rows().enumerate() {
+            for (x, cell) in row.iter().enumerate() {
+                if color && self.highlights.contains(&(x, y)) {
+                    out.push_str(&format!("{HIGHLIGHT}{cell}{RESET}"));
+                } else {
+                    out.push_str(cell);
+                }
+            }
+            out.push('\n');
+        }
+        out.push_str(&self.caption);
+
+        out
+    }
+}
+
+/// Collects frames from a solver, or drops them unbuilt when switched off.
+#[derive(Debug, Default)]
+pub struct Recorder {
+    frames: Option<Vec<Frame>>,
+}
+
+impl Recorder {
+    pub fn on() -> Self {
+        Self {
+            frames: Some(vec![]),
+        }
+    }
+
+    pub fn off() -> Self {
+        Self::default()
+    }
+
+    /// Adds the frame built by `frame`, which is only called when recording.
+    pub fn record(&mut self, frame: impl FnOnce() -> Frame) {
+        if let Some(frames) = &mut self.frames {
+            frames.push(frame());
+        }
+    }
+
+    pub fn into_frames(self) -> Vec<Frame> {
+        self.frames.unwrap_or_default()
+    }
+}
+
+fn frame_delay(fps: f64) -> Duration {
+    Duration::from_secs_f64(1.0 / fps.max(0.1))
+}
+
+/// Draws each frame over the last one, `fps` times a second.
+pub fn play(frames: &[Frame], fps: f64, color: bool, out: &mut impl Write) -> Result<()> {
+    for frame in frames {
+        writeln!(out, "{CLEAR}{}", frame.render(color))?;
+        out.flush()?;
+        thread::sleep(frame_delay(fps));
+    }
+
+    Ok(())
+}
+
+/// The frames as an asciicast v2 recording, one output event per frame.
+pub fn asciicast(frames: &[Frame], fps: f64) -> Result<String> {
+    let width = frames
+        .iter()
+        .map(|frame| frame.cells.width().max(frame.caption.chars().count()))
+        .max()
+        .unwrap_or(0);
+    let height = frames
+        .iter()
+        .map(|frame| frame.cells.height() + 1)
+        .max()
+        .unwrap_or(0);
+    let delay = frame_delay(fps).as_secs_f64();
+
+    let mut lines = vec![serde_json::to_string(
+        &json!({"version": 2, "width": width, "height": height}),
+    )?];
+    for (idx, frame) in frames.iter().enumerate() {
+        let output = format!("{CLEAR}{}\r\n", frame.render(true).replace('\n', "\r\n"));
+        lines.push(serde_json::to_string(&json!([
+            idx as f64 * delay,
+            "o",
+            output
+        ]))?);
+    }
+
+    Ok(lines.join("\n") + "\n")
+}
+
+#[cfg(test)]
+mod viz_tests {
+    use super::*;
+
+    fn frames() -> Result<Vec<Frame>> {
+        let grid = Grid::parse("#.\n.#", |c| c)?;
+        let mut recorder = Recorder::on();
+        recorder.record(|| Frame::new(&grid, "start"));
+        recorder.record(|| Frame::new(&grid, "step 1").highlight([(1, 1)]));
+
+        Ok(recorder.into_frames())
+    }
+
+    #[test]
+    fn test_render() -> Result<()> {
+        let frames = frames()?;
+
+        assert_eq!(frames[0].render(true), "#.\n.#\nstart");
+        assert_eq!(
+            frames[1].render(true),
+            format!("#.\n.{HIGHLIGHT}#{RESET}\nstep 1")
+        );
+        assert_eq!(frames[1].render(false), "#.\n.#\nstep 1");
+        Ok(())
+    }
+
+    #[test]
+    fn test_off_recorder_builds_nothing() {
+        let mut recorder = Recorder::off();
+        recorder.record(|| unreachable!("frames are not built when recording is off"));
+
+        assert!(recorder.into_frames().is_empty());
+    }
+
+    #[test]
+    fn test_asciicast() -> Result<()> {
+        let cast = asciicast(&frames()?, 4.0)?;
+        let lines = cast
+            .lines()
+            .map(serde_json::from_str)
+            .collect::<serde_json::Result<Vec<serde_json::Value>>>()?;
+
+        assert_eq!(lines.len(), 3);
+        assert_eq!(lines[0], json!({"version": 2, "width": 6, "height": 3}));
+        assert_eq!(lines[2][0], json!(0.25));
+        assert_eq!(lines[2][1], json!("o"));
+        assert!(lines[2][2].as_str().unwrap_or("").ends_with("step 1\r\n"));
+        Ok(())
+    }
+}
@@ -0,0 +1,327 @@
+use crate::core::{Problem, day_name, input_dir};
+use anyhow::{Context, Result, anyhow};
+use std::collections::BTreeMap;
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Output};
+use std::thread;
+use std::time::{Duration, SystemTime};
+
+/// A part's answer as printed by the day's binary, or why it failed.
+pub type PartOutcome = std::result::Result<String, String>;
+
+/// Modification time and length of every file under a set of directories.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Snapshot {
+    files: BTreeMap<PathBuf, (SystemTime, u64)>,
+}
+
+impl Snapshot {
+    /// Walks each root, not descending into `skip`; a root that does not exist yet is empty.
+    pub fn scan(roots: &[PathBuf], skip: &[PathBuf]) -> Result<Self> {
+        let mut snapshot = Self::default();
+        for root in roots {
+            snapshot.walk(root, skip)?;
+        }
+
+        Ok(snapshot)
+    }
+
+    fn walk(&mut self, dir: &Path, skip: &[PathBuf]) -> Result<()> {
+        if !dir.is_dir() {
+            return Ok(());
+        }
+
+        for entry in
+            fs::read_dir(dir).with_context(|| format!("failed to list {}", dir.display()))?
+        {
+            let path = entry?.path();
+            let metadata = fs::metadata(&path)?;
+
+            if metadata.is_dir() {
+                if !skip.contains(&path) {
+                    self.walk(&path, skip)?;
+                }
+            } else {
+                self.files
+                    .insert(path, (metadata.modified()?, metadata.len()));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Files added, removed or modified in `newer`.
+    pub fn changes<'a>(&'a self, newer: &'a Snapshot) -> Vec<&'a Path> {
+        let removed = self
+            .files
+            .keys()
+            .filter(|path| !newer.files.contains_key(*path));
+        let touched = newer
+            .files
+            .iter()
+            .filter(|(path, stamp)| self.files.get(*path) != Some(stamp))
+            .map(|(path, _)| path);
+
+        removed.chain(touched).map(PathBuf::as_path).collect()
+    }
+}
+
+/// One line per part comparing this run's answer with the last run's.
+pub fn diff(
+    previous: Option<&[(Problem, PartOutcome)]>,
+    current: &[(Problem, PartOutcome)],
+) -> Vec<String> {
+    let show = |outcome: &PartOutcome| match outcome {
+        Ok(answer) => answer.clone(),
+        Err(_) => String::from("FAILED"),
+    };
+
+    current
+        .iter()
+        .map(|(part, outcome)| {
+            let before = previous
+                .and_then(|runs| runs.iter().find(|(p, _)| p == part))
+                .map(|(_, outcome)| outcome);
+            let change = match before {
+                None => show(outcome),
+                Some(before) if before == outcome => format!("{} (unchanged)", show(outcome)),
+                Some(before) => format!("{} -> {}", show(before), show(outcome)),
+            };
+            let reason = match outcome {
+                Err(error) => format!(": {error}"),
+                Ok(_) => String::new(),
+            };
+
+            format!("part {part}: {change}{reason}")
+        })
+        .collect()
+}
+
+fn cargo(root: &Path) -> Command {
+    let mut command = Command::new(env::var_os("CARGO").unwrap_or_else(|| "cargo".into()));
+    command.current_dir(root);
+    command
+}
+
+fn output(command: &mut Command) -> Result<Output> {
+    command
+        .output()
+        .with_context(|| format!("failed to run {command:?}"))
+}
+
+/// Rebuilds a day's binary whenever its directory or the library changes, then re-runs its
+/// example tests and both parts. Polls every `interval` so it needs no platform file events.
+pub struct Watch {
+    root: PathBuf,
+    day: usize,
+    interval: Duration,
+}
+
+impl Watch {
+    pub fn new(root: &Path, day: usize, interval: Duration) -> Self {
+        Self {
+            root: root.to_path_buf(),
+            day,
+            interval,
+        }
+    }
+
+    /// The library, the day's own binary directory and its input directory, which is the same
+    /// place unless `AOC_INPUT_DIR` moves the inputs elsewhere.
+    fn roots(&self) -> Vec<PathBuf> {
+        let day = day_name(self.day);
+        let mut roots = vec![self.root.join("src"), self.root.join("src/bin").join(&day)];
+
+        let inputs = input_dir().join(&day);
+        if !roots.contains(&inputs) {
+            roots.push(inputs);
+        }
+
+        roots
+    }
+
+    fn scan(&self) -> Result<Snapshot> {
+        Snapshot::scan(&self.roots(), &[self.root.join("src/bin")])
+    }
+
+    /// Builds and solves once, returning `None` when the build fails.
+    fn cycle(&self) -> Result<Option<Vec<(Problem, PartOutcome)>>> {
+        let day = day_name(self.day);
+
+        // Compiler errors go straight to the terminal; everything else is summarized.
+        let build = cargo(&self.root)
+            .args(["build", "--quiet", "--bin", &day])
+            .status()
+            .context("failed to run cargo build")?;
+        if !build.success() {
+            println!("build failed");
+            return Ok(None);
+        }
+
+        let examples = output(cargo(&self.root).args([
+            "test",
+            "--quiet",
+            "--lib",
+            &format!("day_{}_tests::test_problem", self.day),
+        ]))?;
+        if examples.status.success() {
+            println!("examples ok");
+        } else {
+            println!(
+                "examples FAILED\n{}",
+                String::from_utf8_lossy(&examples.stdout).trim()
+            );
+        }
+
+        let mut answers = vec![];
+        for part in [Problem::One, Problem::Two] {
+            let run = output(cargo(&self.root).args([
+                "run",
+                "--quiet",
+                "--bin",
+                &day,
+                "--",
+                "--part",
+                &part.to_string(),
+            ]))?;
+            let outcome = if run.status.success() {
+                Ok(String::from_utf8_lossy(&run.stdout).trim().to_string())
+            } else {
+                let stderr = String::from_utf8_lossy(&run.stderr);
+                Err(stderr
+                    .lines()
+                    .last()
+                    .unwrap_or("no output")
+                    .trim()
+                    .to_string())
+            };
+            answers.push((part, outcome));
+        }
+
+        Ok(Some(answers))
+    }
+
+    pub fn run(&self) -> Result<()> {
+        if !self.root.join("src/bin").join(day_name(self.day)).is_dir() {
+            return Err(anyhow!("day {} has no binary to watch", self.day));
+        }
+
+        let mut snapshot = self.scan()?;
+        let mut previous: Option<Vec<(Problem, PartOutcome)>> = None;
+
+        loop {
+            if let Some(answers) = self.cycle()? {
+                for line in diff(previous.as_deref(), &answers) {
+                    println!("{line}");
+                }
+                previous = Some(answers);
+            }
+            println!("watching day {} for changes...", self.day);
+
+            loop {
+                thread::sleep(self.interval);
+                let next = self.scan()?;
+                let changes = snapshot
+                    .changes(&next)
+                    .iter()
+                    .map(|path| {
+                        path.strip_prefix(&self.root)
+                            .unwrap_or(path)
+                            .display()
+                            .to_string()
+                    })
+                    .collect::<Vec<String>>();
+
+                if !changes.is_empty() {
+                    println!("\nchanged: {}", changes.join(", "));
+                    snapshot = next;
+                    break;
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod watch_tests {
+    use super::*;
+
+    #[test]
+    fn test_snapshot_changes() -> Result<()> {
+        let root = env::temp_dir().join(format!("aoc-watch-{}", std::process::id()));
+        let skipped = root.join("skipped");
+        let (roots, skip) = ([root.clone()], [skipped.clone()]);
+        fs::create_dir_all(root.join("nested"))?;
+        fs::create_dir_all(&skipped)?;
+        fs::write(root.join("input.txt"), "R5")?;
+        fs::write(root.join("nested/lib.rs"), "")?;
+
+        let before = Snapshot::scan(&roots, &skip)?;
+        fs::write(root.join("input.txt"), "R5\nL10")?;
+        fs::write(skipped.join("ignored.rs"), "")?;
+        fs::remove_file(root.join("nested/lib.rs"))?;
+        let after = Snapshot::scan(&roots, &skip)?;
+        let missing = Snapshot::scan(&[root.join("missing")], &[])?;
+        fs::remove_dir_all(&root)?;
+
+        assert_eq!(
+            before.changes(&after),
+            vec![root.join("nested/lib.rs"), root.join("input.txt")]
+        );
+        assert!(after.changes(&after).is_empty());
+        assert_eq!(missing, Snapshot::default());
+        Ok(())
+    }
+
+    #[test]
+    fn test_roots_always_include_the_day_binary() {
+        let manifest = Path::new(env!("CARGO_MANIFEST_DIR"));
+        let elsewhere = env::temp_dir().join("aoc-watch-roots");
+        let interval = Duration::from_secs(1);
+
+        let roots = Watch::new(&elsewhere, 1, interval).roots();
+        assert_eq!(
+            roots,
+            vec![
+                elsewhere.join("src"),
+                elsewhere.join("src/bin/day-1"),
+                input_dir().join("day-1"),
+            ]
+        );
+
+        let roots = Watch::new(manifest, 1, interval).roots();
+        assert!(roots.contains(&manifest.join("src/bin/day-1")));
+        assert!(roots.contains(&input_dir().join("day-1")));
+        assert_eq!(
+            roots.len(),
+            if input_dir() == manifest.join("src/bin") {
+                2
+            } else {
+                3
+            }
+        );
+    }
+
+    #[test]
+    fn test_diff() {
+        let first = vec![
+            (Problem::One, Ok(String::from("3"))),
+            (Problem::Two, Err(String::from("not solved yet"))),
+        ];
+        let second = vec![
+            (Problem::One, Ok(String::from("3"))),
+            (Problem::Two, Ok(String::from("6"))),
+        ];
+
+        assert_eq!(
+            diff(None, &first),
+            vec!["part one: 3", "part two: FAILED: not solved yet"]
+        );
+        assert_eq!(
+            diff(Some(&first), &second),
+            vec!["part one: 3 (unchanged)", "part two: FAILED -> 6"]
+        );
+    }
+}